@@ -1,49 +1,35 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
+use advent_of_code_2023::{get_context, get_input, solvers};
+
+// every registered day is benched, filter by day with `cargo bench -- 2023/day05`
 fn criterion_benchmark(c: &mut Criterion) {
-    macro_rules! bench_day {
-        ($day:tt) => {{
-            const INPUT: &str = include_str!(concat!("../input/", stringify!($day), ".txt"));
-            c.bench_function(stringify!($day), |b| {
-                b.iter(|| advent_of_code_2023::days::$day::solve(black_box(INPUT)))
+    for (input_name, is_test) in [("real", false), ("example", true)] {
+        let inputs = solvers()
+            .filter_map(|solver| {
+                // days without a downloaded input are skipped rather than failing the whole run
+                let input = get_input(solver.year, solver.name, is_test).ok()?;
+                let ctx = get_context(solver.year, solver.name, is_test).unwrap();
+                Some((solver, input, ctx))
+            })
+            .collect::<Vec<_>>();
+
+        let mut group = c.benchmark_group(format!("{input_name}/parse"));
+        for (solver, input, ctx) in &inputs {
+            group.bench_function(solver.label(), |b| {
+                b.iter(|| (solver.parse)(black_box(input), ctx))
             });
-            // const INPUT_TEST: &str =
-            //     include_str!(concat!("../input/", stringify!($day), "_test.txt"));
-            // c.bench_function(concat!(stringify!($day), " test"), |b| {
-            //     b.iter(|| advent_of_code_2023::days::$day::solve(black_box(INPUT_TEST)))
-            // });
-        }};
-        ($day:tt, is_test) => {{
-            const INPUT: &str = include_str!(concat!("../input/", stringify!($day), ".txt"));
-            c.bench_function(stringify!($day), |b| {
-                b.iter(|| advent_of_code_2023::days::$day::solve(black_box(INPUT), false))
+        }
+        group.finish();
+
+        let mut group = c.benchmark_group(format!("{input_name}/solve"));
+        for (solver, input, ctx) in &inputs {
+            group.bench_function(solver.label(), |b| {
+                b.iter(|| (solver.solve)(black_box(input), ctx))
             });
-            // const INPUT_TEST: &str =
-            //     include_str!(concat!("../input/", stringify!($day), "_test.txt"));
-            // c.bench_function(concat!(stringify!($day), " test"), |b| {
-            //     b.iter(|| advent_of_code_2023::days::$day::solve(black_box(INPUT_TEST), true))
-            // });
-        }};
+        }
+        group.finish();
     }
-
-    // bench_day!(day01);
-    // bench_day!(day02);
-    // bench_day!(day03);
-    // bench_day!(day04, is_test);
-    // bench_day!(day05);
-    // bench_day!(day06);
-    // bench_day!(day07);
-    // bench_day!(day08, is_test);
-    // bench_day!(day09);
-    // bench_day!(day10);
-    // bench_day!(day11, is_test);
-    // bench_day!(day12);
-    // bench_day!(day13);
-    // bench_day!(day14);
-    // bench_day!(day15);
-    // bench_day!(day16);
-    // bench_day!(day17);
-    bench_day!(day18);
 }
 
 criterion_group!(benches, criterion_benchmark);
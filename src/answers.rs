@@ -0,0 +1,275 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{Display, Formatter},
+    io::ErrorKind,
+    path::Path,
+};
+
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+
+use crate::{Answers, DayResult};
+
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// Known-good answers keyed by year, day name and then input name, e.g. `[2023.day01.real]`
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct AnswerBook {
+    years: BTreeMap<String, BTreeMap<String, BTreeMap<String, RecordedAnswers>>>,
+}
+
+impl AnswerBook {
+    pub fn load(path: impl AsRef<Path>) -> anyhow::Result<AnswerBook> {
+        let path = path.as_ref();
+        match std::fs::read_to_string(path) {
+            Ok(contents) => toml::from_str(&contents)
+                .with_context(|| format!("failed to parse answers file {path:?}")),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(AnswerBook::default()),
+            Err(err) => Err(err).with_context(|| format!("failed to read answers file {path:?}")),
+        }
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> anyhow::Result<()> {
+        let path = path.as_ref();
+        let contents = toml::to_string_pretty(self).context("failed to serialise answers")?;
+        std::fs::write(path, contents)
+            .with_context(|| format!("failed to write answers file {path:?}"))
+    }
+
+    pub fn get(&self, year: u32, day: &str, input: &str) -> Option<&RecordedAnswers> {
+        self.years.get(&year.to_string())?.get(day)?.get(input)
+    }
+
+    /// fails without recording anything if an answer can't be read, e.g. an unknown drawn letter
+    pub fn record(
+        &mut self,
+        year: u32,
+        day: &str,
+        input: &str,
+        result: &DayResult,
+    ) -> anyhow::Result<()> {
+        let part1 = result.answer(1).map(RecordedAnswer::try_from).transpose()?;
+        let part2 = result.answer(2).map(RecordedAnswer::try_from).transpose()?;
+        let recorded = self
+            .years
+            .entry(year.to_string())
+            .or_default()
+            .entry(day.to_string())
+            .or_default()
+            .entry(input.to_string())
+            .or_default();
+        if part1.is_some() {
+            recorded.part1 = part1;
+        }
+        if part2.is_some() {
+            recorded.part2 = part2;
+        }
+
+        Ok(())
+    }
+
+    pub fn check(&self, year: u32, day: &str, input: &str, result: &DayResult) -> [PartCheck; 2] {
+        match self.get(year, day, input) {
+            Some(recorded) => recorded.check(result),
+            None => [PartCheck::Missing, PartCheck::Missing],
+        }
+    }
+}
+
+/// panics unless `result` matches what [`ANSWERS_FILE`] records for `input`, a solved part needs a
+/// recorded answer
+#[cfg(test)]
+#[track_caller]
+pub(crate) fn assert_recorded(year: u32, day: &str, input: &str, result: &DayResult) {
+    let answers = AnswerBook::load(ANSWERS_FILE).unwrap();
+    let checks = answers.check(year, day, input, result);
+    for (part, check) in (1..).zip(checks) {
+        if result.answer(part).is_some() {
+            assert_ne!(
+                PartCheck::Missing,
+                check,
+                "{year} {day} {input} part {part} has no recorded answer"
+            );
+        }
+        assert!(
+            !check.is_fail(),
+            "{year} {day} {input} part {part}: {check}"
+        );
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordedAnswers {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part1: Option<RecordedAnswer>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part2: Option<RecordedAnswer>,
+}
+
+impl RecordedAnswers {
+    pub fn is_empty(&self) -> bool {
+        self.part1.is_none() && self.part2.is_none()
+    }
+
+    pub fn check(&self, result: &DayResult) -> [PartCheck; 2] {
+        [
+            PartCheck::new(self.part1.as_ref(), &result.part1),
+            PartCheck::new(self.part2.as_ref(), &result.part2),
+        ]
+    }
+}
+
+/// Answers are stored as toml integers where they fit, otherwise as strings
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum RecordedAnswer {
+    Integer(i64),
+    Text(String),
+}
+
+impl Display for RecordedAnswer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RecordedAnswer::Integer(n) => write!(f, "{n}"),
+            RecordedAnswer::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+impl TryFrom<&Answers> for RecordedAnswer {
+    type Error = anyhow::Error;
+
+    fn try_from(answer: &Answers) -> anyhow::Result<Self> {
+        let text = answer.text()?;
+        Ok(match text.parse() {
+            Ok(n) => RecordedAnswer::Integer(n),
+            Err(_) => RecordedAnswer::Text(text),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PartCheck {
+    Pass,
+    Fail {
+        expected: String,
+        actual: Option<String>,
+    },
+    Missing,
+    /// the part failed, whether or not an answer is recorded for it
+    Error(String),
+}
+
+impl PartCheck {
+    fn new(
+        expected: Option<&RecordedAnswer>,
+        actual: &anyhow::Result<Option<Answers>>,
+    ) -> PartCheck {
+        let actual = match actual {
+            Ok(actual) => actual.as_ref(),
+            Err(err) => return PartCheck::Error(format!("{err:#}")),
+        };
+        let Some(expected) = expected else {
+            return PartCheck::Missing;
+        };
+        let expected = expected.to_string();
+        let actual = match actual.map(Answers::text).transpose() {
+            Ok(actual) => actual,
+            Err(err) => return PartCheck::Error(format!("{err:#}")),
+        };
+        if actual.as_ref() == Some(&expected) {
+            PartCheck::Pass
+        } else {
+            PartCheck::Fail { expected, actual }
+        }
+    }
+
+    pub fn is_fail(&self) -> bool {
+        matches!(self, PartCheck::Fail { .. } | PartCheck::Error(_))
+    }
+}
+
+impl Display for PartCheck {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PartCheck::Pass => write!(f, "pass"),
+            PartCheck::Fail { expected, actual } => write!(
+                f,
+                "fail (expected {expected}, got {actual})",
+                actual = actual.as_deref().unwrap_or("TBC")
+            ),
+            PartCheck::Missing => write!(f, "missing"),
+            PartCheck::Error(err) => write!(f, "error: {err}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+
+    use crate::{
+        answers::{assert_recorded, AnswerBook, PartCheck, RecordedAnswer, RecordedAnswers},
+        get_context, get_input,
+        ocr::Grid,
+        solvers, IntoDayResult,
+    };
+
+    #[test]
+    fn recorded_answers_match_solvers() {
+        for solver in solvers() {
+            for (input_name, is_test) in [("real", false), ("test", true)] {
+                let input = get_input(solver.year, solver.name, is_test).unwrap();
+                let ctx = get_context(solver.year, solver.name, is_test).unwrap();
+                let solution = (solver.solve)(&input, &ctx).unwrap();
+                assert_recorded(solver.year, solver.name, input_name, &solution);
+            }
+        }
+    }
+
+    #[test]
+    fn failed_parts_are_reported() {
+        let recorded = RecordedAnswers {
+            part1: Some(RecordedAnswer::Integer(3)),
+            part2: None,
+        };
+        let result = (3, Err::<u64, _>(anyhow!("no cycle found"))).into_day_result();
+        let checks = recorded.check(&result);
+        assert_eq!(
+            [
+                PartCheck::Pass,
+                PartCheck::Error("no cycle found".to_string())
+            ],
+            checks
+        );
+        assert!(checks[1].is_fail());
+
+        let mut answers = AnswerBook::default();
+        answers.record(2023, "day08", "real", &result).unwrap();
+        assert_eq!(Some(&recorded), answers.get(2023, "day08", "real"));
+    }
+
+    #[test]
+    fn unreadable_drawings_are_reported() {
+        let recorded = RecordedAnswers {
+            part1: Some(RecordedAnswer::Text("AB".to_string())),
+            part2: None,
+        };
+        let result = (Grid::new(["####"; 6].join("\n")),).into_day_result();
+        assert_eq!(
+            [
+                PartCheck::Error(
+                    "couldn't read the drawn answer: unknown letter at column 0".to_string()
+                ),
+                PartCheck::Missing
+            ],
+            recorded.check(&result)
+        );
+
+        let mut answers = AnswerBook::default();
+        let err = answers.record(2023, "day10", "real", &result).unwrap_err();
+        assert_eq!("couldn't read the drawn answer", err.to_string());
+        assert_eq!(None, answers.get(2023, "day10", "real"));
+    }
+}
@@ -0,0 +1,578 @@
+use std::{
+    ffi::OsString,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context};
+use sha2::{Digest, Sha256};
+use tracing::info;
+
+use crate::PackageName;
+
+/// The local copy of downloaded inputs, kept per account as `{account}/{year}/dayNN.txt`
+///
+/// Accounts are a hash of the session cookie, so switching sessions never serves
+/// another account's input and the cookie itself isn't written to disk. Inputs cached before
+/// that, as `{year}_dayNN.txt`, are moved under the first account to read them.
+pub struct InputCache {
+    dir: PathBuf,
+}
+
+/// A cached input, as found on disk
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub account: String,
+    pub year: usize,
+    pub day: u32,
+    pub path: PathBuf,
+}
+
+impl InputCache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        InputCache { dir: dir.into() }
+    }
+
+    /// `AOC_CACHE` if set, otherwise `aoc` in the XDG cache dir
+    pub fn from_env() -> anyhow::Result<Self> {
+        let dir = default_dir(
+            std::env::var_os("AOC_CACHE"),
+            std::env::var_os("XDG_CACHE_HOME"),
+            std::env::var_os("HOME"),
+        )?;
+        Ok(InputCache::new(dir))
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn path(&self, account: &str, year: usize, pkg_name: PackageName) -> PathBuf {
+        self.dir
+            .join(account)
+            .join(year.to_string())
+            .join(format!("{pkg_name}.txt"))
+    }
+
+    /// where an input was cached before the cache was kept per account
+    fn legacy_path(&self, year: usize, pkg_name: PackageName) -> PathBuf {
+        self.dir.join(format!("{year}_{pkg_name}.txt"))
+    }
+
+    /// the cached input, if this account has fetched it before
+    pub fn get(
+        &self,
+        account: &str,
+        year: usize,
+        pkg_name: PackageName,
+    ) -> anyhow::Result<Option<String>> {
+        let path = self.path(account, year, pkg_name);
+        match std::fs::read_to_string(&path) {
+            Ok(cached) => Ok(Some(cached)),
+            Err(err) if err.kind() == ErrorKind::NotFound => {
+                if self.migrate(account, year, pkg_name)? {
+                    self.get(account, year, pkg_name)
+                } else {
+                    Ok(None)
+                }
+            }
+            Err(err) => Err(err).with_context(|| format!("failed to read cached input {path:?}")),
+        }
+    }
+
+    /// moves an input from the old unkeyed layout to `account`, unless it has its own copy already
+    ///
+    /// The old layout didn't record whose input it was, so the session using the cache is assumed
+    /// to be the one that fetched it.
+    fn migrate(&self, account: &str, year: usize, pkg_name: PackageName) -> anyhow::Result<bool> {
+        let legacy = self.legacy_path(year, pkg_name);
+        let path = self.path(account, year, pkg_name);
+        if !legacy.is_file() || path.exists() {
+            return Ok(false);
+        }
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)
+                .with_context(|| format!("failed to create cache directory {dir:?}"))?;
+        }
+        std::fs::rename(&legacy, &path)
+            .with_context(|| format!("failed to move cached input {legacy:?} to {path:?}"))?;
+        info!("moved cached input {legacy:?} to {path:?}");
+
+        Ok(true)
+    }
+
+    /// moves every input still in the old unkeyed layout to `account`
+    pub fn migrate_all(&self, account: &str) -> anyhow::Result<()> {
+        for (name, _) in files(&self.dir)? {
+            let parsed = name.strip_suffix(".txt").and_then(|name| {
+                let (year, day) = name.split_once("_day")?;
+                Some((year.parse().ok()?, day.parse().ok()?))
+            });
+            if let Some((year, day)) = parsed {
+                self.migrate(account, year, PackageName(day))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn put(
+        &self,
+        account: &str,
+        year: usize,
+        pkg_name: PackageName,
+        input: &str,
+    ) -> anyhow::Result<()> {
+        let path = self.path(account, year, pkg_name);
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)
+                .with_context(|| format!("failed to create cache directory {dir:?}"))?;
+        }
+        std::fs::write(&path, input).with_context(|| format!("failed to cache input {path:?}"))?;
+        info!("cached input to {path:?}");
+
+        Ok(())
+    }
+
+    /// every cached input in account, year & day order, files that don't fit the layout are skipped
+    pub fn entries(&self) -> anyhow::Result<Vec<Entry>> {
+        let mut entries = vec![];
+        for (account, account_dir) in subdirs(&self.dir)? {
+            for (year, year_dir) in subdirs(&account_dir)? {
+                let Ok(year) = year.parse() else {
+                    continue;
+                };
+                for (name, path) in files(&year_dir)? {
+                    let day = name
+                        .strip_prefix("day")
+                        .and_then(|name| name.strip_suffix(".txt"))
+                        .and_then(|day| day.parse().ok());
+                    if let Some(day) = day {
+                        entries.push(Entry {
+                            account: account.clone(),
+                            year,
+                            day,
+                            path,
+                        });
+                    }
+                }
+            }
+        }
+        entries.sort_by(|a, b| (&a.account, a.year, a.day).cmp(&(&b.account, b.year, b.day)));
+
+        Ok(entries)
+    }
+}
+
+fn default_dir(
+    aoc_cache: Option<OsString>,
+    xdg_cache_home: Option<OsString>,
+    home: Option<OsString>,
+) -> anyhow::Result<PathBuf> {
+    let non_empty = |var: Option<OsString>| var.filter(|var| !var.is_empty()).map(PathBuf::from);
+    if let Some(dir) = non_empty(aoc_cache) {
+        return Ok(dir);
+    }
+    if let Some(dir) = non_empty(xdg_cache_home) {
+        return Ok(dir.join("aoc"));
+    }
+    match non_empty(home) {
+        Some(home) => Ok(home.join(".cache").join("aoc")),
+        None => bail!("failed to find a cache dir, set AOC_CACHE"),
+    }
+}
+
+/// the account a session belongs to in the cache
+pub fn account(session: &str) -> String {
+    let digest = Sha256::digest(session.trim().as_bytes());
+    digest[..8].iter().map(|b| format!("{b:02x}")).collect()
+}
+
+fn read_dir(dir: &Path) -> anyhow::Result<Vec<(String, PathBuf, bool)>> {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == ErrorKind::NotFound => return Ok(vec![]),
+        Err(err) => return Err(err).with_context(|| format!("failed to read {dir:?}")),
+    };
+    let mut found = vec![];
+    for entry in entries {
+        let entry = entry.with_context(|| format!("failed to read {dir:?}"))?;
+        let is_dir = entry
+            .file_type()
+            .with_context(|| format!("failed to read {dir:?}"))?
+            .is_dir();
+        if let Ok(name) = entry.file_name().into_string() {
+            found.push((name, entry.path(), is_dir));
+        }
+    }
+
+    Ok(found)
+}
+
+fn subdirs(dir: &Path) -> anyhow::Result<Vec<(String, PathBuf)>> {
+    Ok(read_dir(dir)?
+        .into_iter()
+        .filter(|&(_, _, is_dir)| is_dir)
+        .map(|(name, path, _)| (name, path))
+        .collect())
+}
+
+fn files(dir: &Path) -> anyhow::Result<Vec<(String, PathBuf)>> {
+    Ok(read_dir(dir)?
+        .into_iter()
+        .filter(|&(_, _, is_dir)| !is_dir)
+        .map(|(name, path, _)| (name, path))
+        .collect())
+}
+
+/// why `input` can't be a real puzzle input, e.g. an error page saved by mistake
+pub fn problem(input: &str) -> Option<&'static str> {
+    let start = input.trim_start().to_ascii_lowercase();
+    if input.trim().is_empty() {
+        Some("empty")
+    } else if start.starts_with("<!doctype") || start.starts_with("<html") {
+        Some("an html page")
+    } else if input.contains("Please log in") {
+        Some("a login prompt")
+    } else if input.contains("before it unlocks") {
+        Some("a locked puzzle")
+    } else if !input.ends_with('\n') {
+        Some("truncated")
+    } else {
+        None
+    }
+}
+
+#[derive(clap::Args, Debug)]
+pub struct CacheArgs {
+    #[command(subcommand)]
+    command: CacheCommand,
+}
+
+#[derive(clap::Subcommand, Debug)]
+enum CacheCommand {
+    /// show every cached input and the account it belongs to
+    List,
+    /// check cached inputs look like real inputs and match the ones in `input/`
+    Verify,
+    /// delete cached inputs that fail verification
+    Prune {
+        /// also delete inputs fetched by other sessions
+        #[arg(long, default_value_t = false)]
+        other_accounts: bool,
+    },
+    /// copy the current session's cached inputs for a year into `input/`
+    Import {
+        /// the days to copy, every cached day of the year by default
+        days: Vec<u32>,
+        #[arg(short, long, default_value_t = 2023)]
+        year: usize,
+        /// replace inputs in `input/` that differ from the cached copy
+        #[arg(short, long, default_value_t = false)]
+        overwrite: bool,
+    },
+}
+
+pub fn run(root: &Path, args: CacheArgs) -> anyhow::Result<()> {
+    let cache = InputCache::from_env()?;
+    let current = std::env::var("AOC_SESSION")
+        .ok()
+        .map(|session| account(&session));
+    info!("using cache {:?}", cache.dir());
+    if let Some(current) = &current {
+        cache.migrate_all(current)?;
+    }
+
+    match args.command {
+        CacheCommand::List => {
+            for entry in cache.entries()? {
+                let marker = if Some(&entry.account) == current.as_ref() {
+                    " (current session)"
+                } else {
+                    ""
+                };
+                let len = std::fs::metadata(&entry.path)
+                    .with_context(|| format!("failed to read {:?}", entry.path))?
+                    .len();
+                println!(
+                    "{} {}/{} {len} bytes{marker}",
+                    entry.account,
+                    entry.year,
+                    PackageName(entry.day)
+                );
+            }
+        }
+        CacheCommand::Verify => {
+            let mut invalid = 0;
+            for entry in cache.entries()? {
+                let label = format!(
+                    "{} {}/{}",
+                    entry.account,
+                    entry.year,
+                    PackageName(entry.day)
+                );
+                let input = std::fs::read_to_string(&entry.path)
+                    .with_context(|| format!("failed to read {:?}", entry.path))?;
+                if let Some(problem) = problem(&input) {
+                    println!("{label}: {problem}");
+                    invalid += 1;
+                    continue;
+                }
+                if Some(&entry.account) == current.as_ref() {
+                    let local = root.join(local_path(entry.year, PackageName(entry.day)));
+                    match std::fs::read_to_string(&local) {
+                        Ok(local_input) if local_input.is_empty() || local_input == input => {}
+                        Ok(_) => println!("{label}: differs from {local:?}"),
+                        Err(_) => {}
+                    }
+                }
+            }
+            if invalid != 0 {
+                bail!("{invalid} cached inputs are invalid, `aoc cache prune` removes them");
+            }
+        }
+        CacheCommand::Prune { other_accounts } => {
+            if other_accounts && current.is_none() {
+                bail!("AOC_SESSION is needed to tell which account is current");
+            }
+            for entry in cache.entries()? {
+                let input = std::fs::read_to_string(&entry.path)
+                    .with_context(|| format!("failed to read {:?}", entry.path))?;
+                let reason = match problem(&input) {
+                    Some(problem) => problem,
+                    None if other_accounts && Some(&entry.account) != current.as_ref() => {
+                        "another account"
+                    }
+                    None => continue,
+                };
+                std::fs::remove_file(&entry.path)
+                    .with_context(|| format!("failed to remove {:?}", entry.path))?;
+                println!(
+                    "removed {} {}/{}: {reason}",
+                    entry.account,
+                    entry.year,
+                    PackageName(entry.day)
+                );
+            }
+        }
+        CacheCommand::Import {
+            days,
+            year,
+            overwrite,
+        } => {
+            let account =
+                current.context("AOC_SESSION is needed to pick whose inputs to import")?;
+            let imported = import(root, &cache, &account, year, &days, overwrite)?;
+            if imported.is_empty() {
+                println!("nothing to import for {year}");
+            } else {
+                let days = imported
+                    .into_iter()
+                    .map(|day| PackageName(day).to_string())
+                    .collect::<Vec<_>>();
+                println!("imported {year} {}", days.join(", "));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// where the generator puts a day's real input
+fn local_path(year: usize, pkg_name: PackageName) -> PathBuf {
+    PathBuf::from(format!("input/{year}/{pkg_name}.txt"))
+}
+
+/// copies cached inputs into `input/`, only replacing empty placeholders unless `overwrite`
+fn import(
+    root: &Path,
+    cache: &InputCache,
+    account: &str,
+    year: usize,
+    days: &[u32],
+    overwrite: bool,
+) -> anyhow::Result<Vec<u32>> {
+    let days = if days.is_empty() {
+        cache
+            .entries()?
+            .into_iter()
+            .filter(|entry| entry.account == account && entry.year == year)
+            .map(|entry| entry.day)
+            .collect()
+    } else {
+        days.to_vec()
+    };
+
+    let mut imported = vec![];
+    for day in days {
+        let pkg_name = PackageName(day);
+        let input = cache
+            .get(account, year, pkg_name)?
+            .with_context(|| format!("{year} {pkg_name} isn't cached for this session"))?;
+        if let Some(problem) = problem(&input) {
+            bail!("cached {year} {pkg_name} is {problem}");
+        }
+
+        let path = root.join(local_path(year, pkg_name));
+        match std::fs::read_to_string(&path) {
+            Ok(existing) if existing == input => continue,
+            Ok(existing) if !existing.is_empty() && !overwrite => {
+                println!("skipped {year} {pkg_name}: {path:?} differs, --overwrite replaces it");
+                continue;
+            }
+            Ok(_) => {}
+            Err(err) if err.kind() == ErrorKind::NotFound => {}
+            Err(err) => return Err(err).with_context(|| format!("failed to read {path:?}")),
+        }
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).with_context(|| format!("failed to create {dir:?}"))?;
+        }
+        std::fs::write(&path, &input).with_context(|| format!("failed to write {path:?}"))?;
+        imported.push(day);
+    }
+
+    Ok(imported)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{ffi::OsString, path::Path};
+
+    use crate::{
+        cache::{account, default_dir, import, problem, Entry, InputCache},
+        testing::TempDir,
+        PackageName,
+    };
+
+    #[test]
+    fn defaults_to_the_xdg_cache_dir() {
+        let var = |value: &str| Some(OsString::from(value));
+        assert_eq!(
+            Path::new("/tmp/aoc"),
+            default_dir(var("/tmp/aoc"), var("/xdg"), var("/home/me")).unwrap()
+        );
+        assert_eq!(
+            Path::new("/xdg/aoc"),
+            default_dir(None, var("/xdg"), var("/home/me")).unwrap()
+        );
+        assert_eq!(
+            Path::new("/home/me/.cache/aoc"),
+            default_dir(var(""), None, var("/home/me")).unwrap()
+        );
+        assert!(default_dir(None, None, None).is_err());
+    }
+
+    #[test]
+    fn keys_inputs_by_session() {
+        assert_eq!(16, account("session").len());
+        assert_eq!(account("session"), account("session\n"));
+        assert_ne!(account("session"), account("other"));
+
+        let dir = TempDir::new();
+        let cache = InputCache::new(&*dir);
+        cache
+            .put(&account("a"), 2023, PackageName(5), "1\n")
+            .unwrap();
+        cache
+            .put(&account("b"), 2022, PackageName(25), "2\n")
+            .unwrap();
+
+        assert_eq!(
+            Some("1\n".to_string()),
+            cache.get(&account("a"), 2023, PackageName(5)).unwrap()
+        );
+        assert_eq!(
+            None,
+            cache.get(&account("b"), 2023, PackageName(5)).unwrap()
+        );
+        let mut expected = vec![
+            Entry {
+                account: account("a"),
+                year: 2023,
+                day: 5,
+                path: dir.join(account("a")).join("2023/day05.txt"),
+            },
+            Entry {
+                account: account("b"),
+                year: 2022,
+                day: 25,
+                path: dir.join(account("b")).join("2022/day25.txt"),
+            },
+        ];
+        expected.sort_by(|a, b| a.account.cmp(&b.account));
+        assert_eq!(expected, cache.entries().unwrap());
+    }
+
+    #[test]
+    fn moves_unkeyed_inputs_to_the_reading_account() {
+        let dir = TempDir::new();
+        let cache = InputCache::new(&*dir);
+        dir.write("2023_day05.txt", "unkeyed 5\n");
+        dir.write("2023_day06.txt", "unkeyed 6\n");
+        dir.write("2022_day25.txt", "unkeyed 25\n");
+        dir.write("notes.txt", "not an input\n");
+        cache
+            .put(&account("a"), 2022, PackageName(25), "keyed\n")
+            .unwrap();
+
+        assert_eq!(
+            Some("unkeyed 5\n".to_string()),
+            cache.get(&account("a"), 2023, PackageName(5)).unwrap()
+        );
+        assert!(!dir.join("2023_day05.txt").exists());
+        assert_eq!(
+            None,
+            cache.get(&account("b"), 2023, PackageName(5)).unwrap()
+        );
+
+        cache.migrate_all(&account("a")).unwrap();
+        let days = cache.entries().unwrap().into_iter().map(|entry| entry.day);
+        assert_eq!(vec![25, 5, 6], days.collect::<Vec<_>>());
+        // an account's own copy wins over an unkeyed one
+        assert_eq!(
+            Some("keyed\n".to_string()),
+            cache.get(&account("a"), 2022, PackageName(25)).unwrap()
+        );
+        assert!(dir.join("2022_day25.txt").exists());
+        assert!(dir.join("notes.txt").exists());
+    }
+
+    #[test]
+    fn spots_inputs_that_arent() {
+        assert_eq!(None, problem("1 2 3\n"));
+        assert_eq!(Some("empty"), problem(" \n"));
+        assert_eq!(Some("truncated"), problem("1 2 3"));
+        assert_eq!(Some("an html page"), problem("<!DOCTYPE html>\n<html>\n"));
+        assert_eq!(
+            Some("a login prompt"),
+            problem("Puzzle inputs differ by user.  Please log in to get your puzzle input.\n")
+        );
+    }
+
+    #[test]
+    fn imports_without_clobbering_inputs() {
+        let dir = TempDir::new();
+        let cache = InputCache::new(dir.join("cache"));
+        let root = dir.join("root");
+        let account = account("session");
+        for day in [1, 2, 3] {
+            cache
+                .put(&account, 2023, PackageName(day), &format!("{day}\n"))
+                .unwrap();
+        }
+        // the generator leaves an empty placeholder without a session
+        dir.write("root/input/2023/day01.txt", "");
+        dir.write("root/input/2023/day02.txt", "edited\n");
+
+        let imported = import(&root, &cache, &account, 2023, &[], false).unwrap();
+        assert_eq!(vec![1, 3], imported);
+        assert_eq!(
+            "edited\n",
+            std::fs::read_to_string(root.join("input/2023/day02.txt")).unwrap()
+        );
+
+        let imported = import(&root, &cache, &account, 2023, &[2], true).unwrap();
+        assert_eq!(vec![2], imported);
+        assert!(import(&root, &cache, &account, 2023, &[4], false).is_err());
+    }
+}
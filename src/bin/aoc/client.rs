@@ -0,0 +1,85 @@
+use anyhow::Context;
+use reqwest::blocking::{Client, ClientBuilder, RequestBuilder};
+use tracing::info;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// `AOC_BASE_URL` if set, otherwise the real site
+pub fn base_url() -> String {
+    std::env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.to_string())
+}
+
+/// An authenticated client for the advent of code site
+///
+/// `AOC_BASE_URL` points it somewhere other than the real site, e.g. a local stub
+pub struct AocClient {
+    base_url: String,
+    session: String,
+    http: Client,
+}
+
+impl AocClient {
+    pub fn new(base_url: impl Into<String>, session: impl Into<String>) -> anyhow::Result<Self> {
+        let http = ClientBuilder::new()
+            .user_agent("https://github.com/jchevertonwynne/advent-of-code-2023")
+            .build()
+            .context("failed to build http client")?;
+
+        Ok(AocClient {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            session: session.into(),
+            http,
+        })
+    }
+
+    pub fn from_env() -> anyhow::Result<Self> {
+        let session = std::env::var("AOC_SESSION").context("failed to find AOC_SESSION env var")?;
+        AocClient::new(base_url(), session)
+    }
+
+    /// the puzzle description page, part 2 is only included with a session
+    pub fn puzzle(&self, year: usize, day: u32) -> anyhow::Result<String> {
+        let url = format!("{}/{year}/day/{day}", self.base_url);
+        info!("retrieving puzzle from url {url}");
+        self.send(self.http.get(url))
+    }
+
+    pub fn input(&self, year: usize, day: u32) -> anyhow::Result<String> {
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+        info!("retrieving input from url {url}");
+        self.send(self.http.get(url))
+    }
+
+    /// a private leaderboard's json, the site asks for this at most every 15 minutes
+    pub fn leaderboard(&self, year: usize, id: u64) -> anyhow::Result<String> {
+        let url = format!(
+            "{}/{year}/leaderboard/private/view/{id}.json",
+            self.base_url
+        );
+        info!("retrieving leaderboard from url {url}");
+        self.send(self.http.get(url))
+    }
+
+    /// the html page describing the outcome of the submission
+    pub fn submit(&self, year: usize, day: u32, part: u8, answer: &str) -> anyhow::Result<String> {
+        let url = format!("{}/{year}/day/{day}/answer", self.base_url);
+        info!("submitting answer to url {url}");
+        let level = part.to_string();
+        self.send(
+            self.http
+                .post(url)
+                .form(&[("level", level.as_str()), ("answer", answer)]),
+        )
+    }
+
+    fn send(&self, request: RequestBuilder) -> anyhow::Result<String> {
+        request
+            .header("Cookie", format!("session={}", self.session))
+            .send()
+            .context("failed to perform http request")?
+            .error_for_status()
+            .context("bad http response code returned")?
+            .text()
+            .context("failed to read http response body")
+    }
+}
@@ -0,0 +1,331 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use anyhow::Context;
+use chrono::{DateTime, Utc};
+use clap::Parser;
+use serde::Deserialize;
+use tracing::info;
+
+use crate::{
+    cache::{self, InputCache},
+    client::{self, AocClient},
+    unlock::{self, Clock},
+};
+
+/// the site asks that private leaderboards aren't fetched more often than this
+pub const MIN_REFRESH: Duration = Duration::from_secs(15 * 60);
+
+#[derive(Parser, Debug)]
+pub struct LeaderboardArgs {
+    /// the leaderboard's id, the number at the end of its url
+    id: u64,
+    #[arg(short, long, default_value_t = 2023)]
+    year: usize,
+    /// print the leaderboard's json as the site returned it
+    #[arg(long, default_value_t = false)]
+    json: bool,
+}
+
+/// A private leaderboard as the site's json api describes it
+#[derive(Debug, Deserialize)]
+pub struct Leaderboard {
+    pub event: String,
+    pub members: BTreeMap<String, Member>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Member {
+    pub id: u64,
+    pub name: Option<String>,
+    pub stars: u32,
+    pub local_score: u64,
+    pub last_star_ts: i64,
+    /// day, then part, to when the star was earned
+    pub completion_day_level: BTreeMap<u32, BTreeMap<u8, Star>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Star {
+    pub get_star_ts: i64,
+}
+
+impl Member {
+    /// the site shows members without a public name by their id
+    pub fn display_name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => format!("(anonymous user #{})", self.id),
+        }
+    }
+
+    fn star_ts(&self, day: u32, part: u8) -> Option<i64> {
+        Some(self.completion_day_level.get(&day)?.get(&part)?.get_star_ts)
+    }
+}
+
+pub fn run(args: LeaderboardArgs, clock: &dyn Clock) -> anyhow::Result<()> {
+    let session = std::env::var("AOC_SESSION").context("failed to find AOC_SESSION env var")?;
+    let client = AocClient::new(client::base_url(), &session)?;
+    let path = cache_path(&InputCache::from_env()?, &session, args.year, args.id);
+    let json = fetch(&path, clock, || client.leaderboard(args.year, args.id))?;
+
+    if args.json {
+        println!("{json}");
+    } else {
+        let leaderboard = serde_json::from_str::<Leaderboard>(&json)
+            .context("failed to parse leaderboard json")?;
+        print!("{}", render(&leaderboard)?);
+    }
+
+    Ok(())
+}
+
+/// leaderboards are only visible to members, so they're cached per account too
+fn cache_path(cache: &InputCache, session: &str, year: usize, id: u64) -> PathBuf {
+    cache
+        .dir()
+        .join(cache::account(session))
+        .join("leaderboards")
+        .join(format!("{year}_{id}.json"))
+}
+
+/// the json cached at `path` if it's younger than `MIN_REFRESH`, otherwise a fresh copy from `download`
+fn fetch(
+    path: &Path,
+    clock: &dyn Clock,
+    download: impl FnOnce() -> anyhow::Result<String>,
+) -> anyhow::Result<String> {
+    match std::fs::metadata(path).and_then(|metadata| metadata.modified()) {
+        Ok(modified) => {
+            let age = clock.now() - DateTime::<Utc>::from(modified);
+            if age.to_std().is_ok_and(|age| age < MIN_REFRESH) {
+                info!(
+                    "serving cached leaderboard from {age}s ago",
+                    age = age.num_seconds()
+                );
+                return std::fs::read_to_string(path)
+                    .with_context(|| format!("failed to read cached leaderboard {path:?}"));
+            }
+        }
+        Err(err) if err.kind() == ErrorKind::NotFound => {}
+        Err(err) => return Err(err).with_context(|| format!("failed to read {path:?}")),
+    }
+
+    let json = download()?;
+    // an unparseable response isn't cached, so the next run tries again
+    serde_json::from_str::<Leaderboard>(&json).context("failed to parse leaderboard json")?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).with_context(|| format!("failed to create {dir:?}"))?;
+    }
+    std::fs::write(path, &json).with_context(|| format!("failed to cache {path:?}"))?;
+
+    Ok(json)
+}
+
+/// how long after `day` unlocked the star at `ts` was earned
+fn solve_time(year: usize, day: u32, ts: Option<i64>) -> anyhow::Result<String> {
+    let Some(ts) = ts else {
+        return Ok("-".to_string());
+    };
+    let earned = DateTime::from_timestamp(ts, 0).context("star timestamp out of range")?;
+    let taken = (earned - unlock::unlock_time(year, day)?)
+        .to_std()
+        .unwrap_or_default();
+    Ok(unlock::format_duration(taken))
+}
+
+/// the members ranked by local score, then their stars per day & how long each part took them
+pub fn render(leaderboard: &Leaderboard) -> anyhow::Result<String> {
+    let year = leaderboard
+        .event
+        .parse()
+        .with_context(|| format!("unknown event {:?}", leaderboard.event))?;
+    let mut members = leaderboard.members.values().collect::<Vec<_>>();
+    members.sort_by_key(|member| {
+        (
+            std::cmp::Reverse(member.local_score),
+            std::cmp::Reverse(member.stars),
+            member.last_star_ts,
+            member.id,
+        )
+    });
+    let last_day = members
+        .iter()
+        .flat_map(|member| member.completion_day_level.keys())
+        .copied()
+        .max()
+        .unwrap_or(0);
+    let days = 1..=last_day;
+    let name_width = members
+        .iter()
+        .map(|member| member.display_name().chars().count())
+        .max()
+        .unwrap_or(0);
+
+    let mut out = String::new();
+    // days are numbered down the header once they reach two digits
+    if last_day >= 10 {
+        let tens = days
+            .clone()
+            .map(|day| match day / 10 {
+                0 => ' ',
+                tens => char::from(b'0' + tens as u8),
+            })
+            .collect::<String>();
+        writeln!(out, "{:17}{tens}", "")?;
+    }
+    let strip = days
+        .clone()
+        .map(|day| char::from(b'0' + (day % 10) as u8))
+        .collect::<String>();
+    writeln!(
+        out,
+        "   # score stars {strip:<w$} name",
+        w = days.clone().count()
+    )?;
+    for (rank, member) in members.iter().enumerate() {
+        let stars = days
+            .clone()
+            .map(
+                |day| match (member.star_ts(day, 1), member.star_ts(day, 2)) {
+                    (Some(_), Some(_)) => '*',
+                    (Some(_), None) => '+',
+                    _ => '.',
+                },
+            )
+            .collect::<String>();
+        writeln!(
+            out,
+            "{rank:>4} {score:>5} {count:>5} {stars} {name}",
+            rank = rank + 1,
+            score = member.local_score,
+            count = member.stars,
+            name = member.display_name()
+        )?;
+    }
+
+    for day in days {
+        let mut solvers = members
+            .iter()
+            .filter(|member| member.star_ts(day, 1).is_some())
+            .collect::<Vec<_>>();
+        if solvers.is_empty() {
+            continue;
+        }
+        solvers.sort_by_key(|member| member.star_ts(day, 1));
+        writeln!(
+            out,
+            "\n{day:<w$}  {:>11}  {:>11}",
+            "part 1",
+            "part 2",
+            day = format!("day {day}"),
+            w = name_width + 2
+        )?;
+        for member in solvers {
+            writeln!(
+                out,
+                "  {name:<name_width$}  {part1:>11}  {part2:>11}",
+                name = member.display_name(),
+                part1 = solve_time(year, day, member.star_ts(day, 1))?,
+                part2 = solve_time(year, day, member.star_ts(day, 2))?,
+            )?;
+        }
+    }
+
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use chrono::Utc;
+
+    use crate::{
+        leaderboard::{fetch, render, Leaderboard, MIN_REFRESH},
+        testing::{TempDir, TestClock},
+    };
+
+    const FIXTURE: &str = include_str!("../../../tests/fixtures/leaderboard.json");
+
+    #[test]
+    fn renders_the_fixture() {
+        let leaderboard = serde_json::from_str::<Leaderboard>(FIXTURE).unwrap();
+        assert_eq!(
+            "   # score stars 12 name
+   1    10     4 ** alice
+   2     8     3 *+ bob
+   3     1     1 +. (anonymous user #202)
+   4     0     0 .. carol
+
+day 1                         part 1       part 2
+  bob                       00:03:20     00:15:00
+  alice                     00:05:12     00:09:40
+  (anonymous user #202)     01:00:00            -
+
+day 2                         part 1       part 2
+  bob                       00:08:20            -
+  alice                     00:16:40  1d 01:01:01
+",
+            render(&leaderboard).unwrap()
+        );
+    }
+
+    #[test]
+    fn pads_names_by_characters() {
+        // the longest name, and longer still in bytes
+        let fixture = FIXTURE.replace("\"alice\"", "\"Åsa Ödegård-Ljungström\"");
+        let leaderboard = serde_json::from_str::<Leaderboard>(&fixture).unwrap();
+        let rendered = render(&leaderboard).unwrap();
+        assert!(
+            rendered.contains(
+                "day 1                          part 1       part 2
+  bob                        00:03:20     00:15:00
+  Åsa Ödegård-Ljungström     00:05:12     00:09:40
+"
+            ),
+            "{rendered}"
+        );
+    }
+
+    #[test]
+    fn refreshes_at_most_every_15_minutes() {
+        let dir = TempDir::new();
+        let path = dir.join("2023_101.json");
+        let downloads = Cell::new(0);
+        let download = || {
+            downloads.set(downloads.get() + 1);
+            Ok(FIXTURE.to_string())
+        };
+
+        let now = Utc::now();
+        assert_eq!(
+            FIXTURE,
+            fetch(&path, &TestClock::new(now), download).unwrap()
+        );
+        fetch(
+            &path,
+            &TestClock::new(now + chrono::Duration::minutes(14)),
+            download,
+        )
+        .unwrap();
+        assert_eq!(1, downloads.get());
+
+        // the cache was written a moment after `now`, so go a little past the limit
+        let later =
+            now + chrono::Duration::from_std(MIN_REFRESH).unwrap() + chrono::Duration::seconds(1);
+        fetch(&path, &TestClock::new(later), download).unwrap();
+        assert_eq!(2, downloads.get());
+
+        // error pages aren't cached
+        std::fs::remove_file(&path).unwrap();
+        assert!(fetch(&path, &TestClock::new(now), || Ok("<html>".to_string())).is_err());
+        assert!(!path.exists());
+    }
+}
@@ -0,0 +1,285 @@
+use anyhow::{anyhow, bail, Context};
+use clap::Parser;
+use tracing::info;
+
+use std::{
+    fmt::{Display, Formatter},
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    cache::{CacheArgs, InputCache},
+    client::AocClient,
+    leaderboard::LeaderboardArgs,
+    plan::Plan,
+    remove::RemoveArgs,
+    status::StatusArgs,
+    submit::SubmitArgs,
+    template::TemplateVars,
+    unlock::{Clock, SystemClock},
+};
+
+mod cache;
+mod client;
+mod leaderboard;
+mod modfile;
+mod plan;
+mod puzzle;
+mod readme;
+mod remove;
+mod status;
+mod submit;
+mod template;
+#[cfg(test)]
+mod testing;
+mod unlock;
+mod workspace;
+mod years;
+
+fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+    setup_tracing()?;
+    let cwd = std::env::current_dir().context("failed to find current dir")?;
+    let root = workspace::find_root(args.root.as_deref(), &cwd)?;
+    info!("using workspace {root:?}");
+    match args.command {
+        Some(Command::Submit(args)) => submit::run(&root, args),
+        Some(Command::Cache(args)) => cache::run(&root, args),
+        Some(Command::Leaderboard(args)) => leaderboard::run(args, &SystemClock),
+        Some(Command::Remove(args)) => remove::run(&root, args),
+        Some(Command::Status(args)) => status::run(&root, args, &SystemClock),
+        None => generate(&root, args.generate, &SystemClock),
+    }
+}
+
+fn generate(root: &Path, args: GenerateArgs, clock: &dyn Clock) -> anyhow::Result<()> {
+    let day = args.day.unwrap_or_else(|| unlock::default_day(clock));
+    let year = args.year;
+    let session = std::env::var("AOC_SESSION").ok();
+    // only fetching needs the puzzle unlocked, stubs for a future day can be written offline
+    let fetches = !args.dry_run && (session.is_some() || args.example.is_some());
+    if fetches && args.wait {
+        unlock::wait_for_unlock(clock, year, day, &mut std::io::stderr())?;
+    } else if fetches {
+        unlock::ensure_unlocked(clock, year, day)?;
+    }
+
+    let pkg_name = PackageName(day);
+    let vars = TemplateVars {
+        day: pkg_name.0,
+        year,
+        pkg_name: pkg_name.to_string(),
+        solution: pkg_name.solution_name(),
+    };
+    let template = template::load(root, args.template.as_deref())?;
+    let test_params = template::uses_test_params(&template);
+    let solver = template::render(&template, &vars).context("could not render solver template")?;
+
+    // everything is downloaded up front, so a failed fetch leaves nothing behind
+    let (input, page) = if !fetches {
+        (None, None)
+    } else {
+        let input = match &session {
+            Some(session) => Some(retrieve_cached_or_fresh_input(pkg_name, year, session)?),
+            None => None,
+        };
+        let page = match args.example {
+            Some(_) => Some(retrieve_puzzle(pkg_name, year)?),
+            None => None,
+        };
+        (input, page)
+    };
+
+    let mut plan = Plan::new(root);
+    plan_runner_file(&mut plan, year, pkg_name, args.overwrite)?;
+    plan_mod_file(&mut plan, year, pkg_name).context("could not update mod file")?;
+    plan.write(format!("src/y{year}/{pkg_name}.rs"), solver)?;
+    let readme = plan
+        .read("README.md")?
+        .with_context(|| format!("failed to read {:?}", root.join("README.md")))?;
+    plan.write(
+        "README.md",
+        readme::add_solution_row(&readme, pkg_name, year),
+    )?;
+
+    let input_note = match &session {
+        Some(_) => "real input, from the cache or downloaded",
+        None => "empty, AOC_SESSION isn't set",
+    };
+    plan.write_noted(
+        format!("input/{year}/{pkg_name}.txt"),
+        input.unwrap_or_default(),
+        Some(input_note.to_string()),
+    )?;
+    let test_input = match (args.example, &page) {
+        (Some(index), Some(page)) => puzzle::example(page, index)?,
+        _ => String::new(),
+    };
+    let test_note = match args.example {
+        Some(index) => format!("example {index} from the puzzle"),
+        None => "empty".to_string(),
+    };
+    plan.write_noted(
+        format!("input/{year}/{pkg_name}_test.txt"),
+        test_input,
+        Some(test_note),
+    )?;
+    if test_params {
+        plan.write_noted(
+            format!("input/{year}/{pkg_name}_test.toml"),
+            "[params]\nsteps = 0\n",
+            Some("the test input's params".to_string()),
+        )?;
+    }
+    if args.example.is_some() {
+        plan.write_noted(
+            format!("puzzles/{year}/{pkg_name}.md"),
+            page.as_deref().map(puzzle::to_markdown).unwrap_or_default(),
+            Some("the puzzle description".to_string()),
+        )?;
+    }
+
+    if args.dry_run {
+        print!("{plan}");
+        return Ok(());
+    }
+    plan.apply()
+}
+
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None, args_conflicts_with_subcommands = true)]
+struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+    /// the package to work in, found from the current dir by default
+    #[arg(long, global = true, value_name = "PATH")]
+    root: Option<PathBuf>,
+    #[command(flatten)]
+    generate: GenerateArgs,
+}
+
+#[derive(clap::Subcommand, Debug)]
+enum Command {
+    /// solve a day's real input and submit the answer for one part
+    Submit(SubmitArgs),
+    /// inspect the downloaded input cache & copy inputs from it into `input/`
+    Cache(CacheArgs),
+    /// show a private leaderboard, refreshed at most every 15 minutes
+    Leaderboard(LeaderboardArgs),
+    /// delete a day's runner, solver & inputs and unregister it
+    Remove(RemoveArgs),
+    /// show which days are solved, stubbed, missing inputs, unchecked or unbenched
+    Status(StatusArgs),
+}
+
+#[derive(clap::Args, Debug)]
+struct GenerateArgs {
+    /// today's puzzle by default, days start at midnight UTC-5
+    #[arg(value_parser = clap::value_parser!(u32).range(1..=25))]
+    day: Option<u32>,
+    #[arg(short, long, default_value_t = 2023)]
+    year: usize,
+    #[arg(short, long, default_value_t = false)]
+    overwrite: bool,
+    /// fill the test input from the puzzle page's first example, or the Nth if given
+    #[arg(short, long, value_name = "N", num_args = 0..=1, default_missing_value = "1")]
+    example: Option<usize>,
+    /// count down to a puzzle that's still locked & generate it as soon as it unlocks
+    #[arg(short, long, default_value_t = false)]
+    wait: bool,
+    /// print the files that would be written without fetching or writing anything
+    #[arg(long, default_value_t = false)]
+    dry_run: bool,
+    /// scaffold the solver from `templates/NAME.rs.tmpl`, e.g. `grid`, `lines` or `params`
+    #[arg(short, long, value_name = "NAME")]
+    template: Option<String>,
+}
+
+fn setup_tracing() -> Result<(), anyhow::Error> {
+    tracing_subscriber::fmt()
+        .try_init()
+        .map_err(|err| anyhow!("failed to setup tracing: {}", err))?;
+
+    Ok(())
+}
+
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+struct PackageName(u32);
+
+impl Display for PackageName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "day{:0>2}", self.0)
+    }
+}
+
+impl PackageName {
+    fn solution_name(&self) -> String {
+        format!("Day{:0>2}", self.0)
+    }
+}
+
+fn plan_runner_file(
+    plan: &mut Plan,
+    year: usize,
+    pkg_name: PackageName,
+    overwrite: bool,
+) -> Result<(), anyhow::Error> {
+    let path = format!("src/bin/y{year}_{pkg_name}.rs");
+    if !overwrite && plan.read(&path)?.is_some() {
+        bail!("runner file {path} already exists, --overwrite replaces it");
+    }
+    let krate = workspace::PACKAGE_NAME.replace('-', "_");
+    plan.write(path, format!("{krate}::aoc!(y{year}, {pkg_name});"))
+}
+
+/// adds `pkg_name` to `src/yYYYY/mod.rs`, creating the year's module if it's the first day of it
+fn plan_mod_file(plan: &mut Plan, year: usize, pkg_name: PackageName) -> Result<(), anyhow::Error> {
+    let mod_path = format!("src/y{year}/mod.rs");
+    let days = match plan.read(&mod_path)? {
+        Some(days) => days,
+        None => {
+            let lib = plan
+                .read("src/lib.rs")?
+                .context("failed to find src/lib.rs")?;
+            plan.write("src/lib.rs", years::add_year(&lib, year)?)?;
+            modfile::new_mod_file(year)
+        }
+    };
+
+    plan.write(mod_path, modfile::add_day(&days, pkg_name)?)
+}
+
+/// the puzzle page, the puzzle is public so a session only adds part 2 once part 1 is solved
+fn retrieve_puzzle(pkg_name: PackageName, year: usize) -> anyhow::Result<String> {
+    let session = std::env::var("AOC_SESSION").unwrap_or_default();
+    AocClient::new(client::base_url(), session)?.puzzle(year, pkg_name.0)
+}
+
+fn retrieve_cached_or_fresh_input(
+    pkg_name: PackageName,
+    year: usize,
+    session: &str,
+) -> Result<String, anyhow::Error> {
+    let cache = InputCache::from_env()?;
+    let account = cache::account(session);
+    if let Some(cached) = cache.get(&account, year, pkg_name)? {
+        info!("serving cached input");
+        return Ok(cached);
+    }
+
+    let input = retrieve_fresh(pkg_name, year, session)?;
+    cache.put(&account, year, pkg_name, &input)?;
+
+    Ok(input)
+}
+
+fn retrieve_fresh(
+    pkg_name: PackageName,
+    year: usize,
+    session: &str,
+) -> Result<String, anyhow::Error> {
+    let response = AocClient::new(client::base_url(), session)?.input(year, pkg_name.0)?;
+    info!("retrieved input");
+
+    Ok(response)
+}
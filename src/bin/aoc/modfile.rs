@@ -0,0 +1,269 @@
+use anyhow::Context;
+use nom::{
+    bytes::complete::tag,
+    character::complete::digit1,
+    combinator::{all_consuming, map, opt},
+    sequence::{delimited, preceded, terminated, tuple},
+    IResult,
+};
+
+use crate::PackageName;
+
+fn parse_mod_line(input: &str) -> IResult<&str, PackageName> {
+    all_consuming(delimited(
+        tag("pub mod "),
+        map(
+            preceded(tag("day"), nom::character::complete::u32),
+            PackageName,
+        ),
+        tag(";"),
+    ))(input)
+}
+
+fn parse_solver_line(input: &str) -> IResult<&str, PackageName> {
+    all_consuming(delimited(
+        tag("solver!("),
+        terminated(
+            map(
+                preceded(tag("day"), nom::character::complete::u32),
+                PackageName,
+            ),
+            preceded(tag(", Day"), digit1),
+        ),
+        tuple((tag(")"), opt(tag(",")))),
+    ))(input)
+}
+
+/// the day a `pub mod dayNN;` line declares, any other line is left alone
+fn mod_line_day(line: &str) -> Option<PackageName> {
+    parse_mod_line(line.trim()).ok().map(|(_, day)| day)
+}
+
+/// the day a `solver!(dayNN, DayNN),` line registers, any other line is left alone
+fn solver_line_day(line: &str) -> Option<PackageName> {
+    parse_solver_line(line.trim()).ok().map(|(_, day)| day)
+}
+
+/// attributes & doc comments belong to the item below them
+fn is_attached(line: &str) -> bool {
+    let line = line.trim_start();
+    line.starts_with("#[") || line.starts_with("///")
+}
+
+/// the first line of the item at `i`, including what's attached to it
+fn item_start(lines: &[String], i: usize) -> usize {
+    let mut start = i;
+    while start > 0 && is_attached(&lines[start - 1]) {
+        start -= 1;
+    }
+    start
+}
+
+/// where `pkg_name` goes among the lines `day_of` recognises, keeping them in day order
+fn sorted_position(
+    lines: &[String],
+    day_of: fn(&str) -> Option<PackageName>,
+    pkg_name: PackageName,
+) -> Option<usize> {
+    let days = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| Some((i, day_of(line)?)))
+        .collect::<Vec<_>>();
+    match days.iter().find(|&&(_, day)| day > pkg_name) {
+        Some(&(i, _)) => Some(item_start(lines, i)),
+        None => days.last().map(|&(i, _)| i + 1),
+    }
+}
+
+fn join(lines: Vec<String>) -> String {
+    let mut joined = lines.join("\n");
+    joined.push('\n');
+    joined
+}
+
+/// a year's `mod.rs` before it has any days
+pub fn new_mod_file(year: usize) -> String {
+    format!(
+        "use crate::{{solver, Solver}};\n\n\
+        pub const YEAR: u32 = {year};\n\n\
+        pub const SOLVERS: &[Solver] = &[\n\
+        ];\n"
+    )
+}
+
+/// the days `mod_file` registers in `SOLVERS`
+pub fn registered_days(mod_file: &str) -> Vec<PackageName> {
+    mod_file.lines().filter_map(solver_line_day).collect()
+}
+
+/// `mod_file` with `pkg_name` declared & registered, every other line is kept as it is
+pub fn add_day(mod_file: &str, pkg_name: PackageName) -> anyhow::Result<String> {
+    let mut lines = mod_file.lines().map(str::to_string).collect::<Vec<_>>();
+
+    if !lines
+        .iter()
+        .any(|line| mod_line_day(line) == Some(pkg_name))
+    {
+        // the first day goes after any other modules, or at the top
+        let at = sorted_position(&lines, mod_line_day, pkg_name).unwrap_or_else(|| {
+            lines
+                .iter()
+                .rposition(|line| line.starts_with("pub mod ") || line.starts_with("mod "))
+                .map_or(0, |i| i + 1)
+        });
+        if at == 0 && lines.first().is_some_and(|line| !line.is_empty()) {
+            lines.insert(0, String::new());
+        }
+        lines.insert(at, format!("pub mod {pkg_name};"));
+    }
+
+    if !lines
+        .iter()
+        .any(|line| solver_line_day(line) == Some(pkg_name))
+    {
+        let at = match sorted_position(&lines, solver_line_day, pkg_name) {
+            Some(at) => at,
+            None => {
+                let start = lines
+                    .iter()
+                    .position(|line| line.starts_with("pub const SOLVERS"))
+                    .context("mod.rs has no SOLVERS registry")?;
+                start
+                    + lines[start..]
+                        .iter()
+                        .position(|line| line.trim() == "];")
+                        .context("mod.rs's SOLVERS registry isn't closed")?
+            }
+        };
+        let indent = lines
+            .iter()
+            .find(|line| solver_line_day(line).is_some())
+            .map_or("    ", |line| &line[..line.len() - line.trim_start().len()]);
+        let entry = format!(
+            "{indent}solver!({pkg_name}, {solution}),",
+            solution = pkg_name.solution_name()
+        );
+        lines.insert(at, entry);
+    }
+
+    Ok(join(lines))
+}
+
+/// `mod_file` without `pkg_name` or anything attached to it, `None` if it isn't there
+pub fn remove_day(mod_file: &str, pkg_name: PackageName) -> Option<String> {
+    let mut lines = mod_file.lines().map(str::to_string).collect::<Vec<_>>();
+    let mut removed = false;
+    while let Some(i) = lines
+        .iter()
+        .position(|line| mod_line_day(line).or(solver_line_day(line)) == Some(pkg_name))
+    {
+        lines.drain(item_start(&lines, i)..=i);
+        removed = true;
+    }
+
+    removed.then(|| join(lines))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        modfile::{add_day, new_mod_file, remove_day},
+        PackageName,
+    };
+
+    const MOD_FILE: &str = "\
+//! 2023's solutions
+pub mod day01;
+/// needs the big stack
+#[cfg(feature = \"slow\")]
+pub mod day07;
+mod helpers;
+
+use crate::{solver, Solver};
+
+pub const YEAR: u32 = 2023;
+
+pub const SOLVERS: &[Solver] = &[
+    solver!(day01, Day01),
+    #[cfg(feature = \"slow\")]
+    solver!(day07, Day07),
+];
+";
+
+    #[test]
+    fn keeps_unrelated_lines() {
+        let updated = add_day(MOD_FILE, PackageName(5)).unwrap();
+        assert_eq!(
+            "\
+//! 2023's solutions
+pub mod day01;
+pub mod day05;
+/// needs the big stack
+#[cfg(feature = \"slow\")]
+pub mod day07;
+mod helpers;
+
+use crate::{solver, Solver};
+
+pub const YEAR: u32 = 2023;
+
+pub const SOLVERS: &[Solver] = &[
+    solver!(day01, Day01),
+    solver!(day05, Day05),
+    #[cfg(feature = \"slow\")]
+    solver!(day07, Day07),
+];
+",
+            updated
+        );
+        assert_eq!(updated, add_day(&updated, PackageName(5)).unwrap());
+
+        let updated = add_day(MOD_FILE, PackageName(12)).unwrap();
+        assert!(updated.contains("pub mod day07;\npub mod day12;\nmod helpers;\n"));
+        assert!(updated.contains("    solver!(day07, Day07),\n    solver!(day12, Day12),\n];"));
+    }
+
+    #[test]
+    fn starts_new_years() {
+        let updated = add_day(&new_mod_file(2022), PackageName(3)).unwrap();
+        assert_eq!(
+            "\
+pub mod day03;
+
+use crate::{solver, Solver};
+
+pub const YEAR: u32 = 2022;
+
+pub const SOLVERS: &[Solver] = &[
+    solver!(day03, Day03),
+];
+",
+            updated
+        );
+    }
+
+    #[test]
+    fn removes_days_with_their_attributes() {
+        let updated = remove_day(MOD_FILE, PackageName(7)).unwrap();
+        assert_eq!(
+            "\
+//! 2023's solutions
+pub mod day01;
+mod helpers;
+
+use crate::{solver, Solver};
+
+pub const YEAR: u32 = 2023;
+
+pub const SOLVERS: &[Solver] = &[
+    solver!(day01, Day01),
+];
+",
+            updated
+        );
+        assert_eq!(None, remove_day(MOD_FILE, PackageName(5)));
+        let round_trip = add_day(MOD_FILE, PackageName(5)).unwrap();
+        assert_eq!(MOD_FILE, remove_day(&round_trip, PackageName(5)).unwrap());
+    }
+}
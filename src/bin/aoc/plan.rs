@@ -0,0 +1,259 @@
+use std::{
+    fmt::{Display, Formatter},
+    io::ErrorKind,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Context;
+use tracing::{info, warn};
+
+/// Every file the generator is about to write or delete, applied together or not at all
+pub struct Plan {
+    root: PathBuf,
+    ops: Vec<Op>,
+}
+
+/// A file to write or delete, along with what it held before so it can be put back
+struct Op {
+    /// relative to the workspace root
+    path: PathBuf,
+    /// `None` deletes the file
+    contents: Option<String>,
+    /// `None` if the file doesn't exist yet
+    previous: Option<Vec<u8>>,
+    /// where the contents come from, when it isn't obvious from the path
+    note: Option<String>,
+}
+
+impl Op {
+    fn action(&self) -> &'static str {
+        match (&self.previous, &self.contents) {
+            (_, None) => "delete",
+            (None, Some(_)) => "create",
+            (Some(previous), Some(contents)) if *previous == contents.as_bytes() => "keep",
+            (Some(_), Some(_)) => "update",
+        }
+    }
+}
+
+impl Plan {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Plan {
+            root: root.into(),
+            ops: vec![],
+        }
+    }
+
+    /// what `path` holds now, planned writes aside
+    pub fn read(&self, path: impl AsRef<Path>) -> anyhow::Result<Option<String>> {
+        let full_path = self.root.join(path);
+        match std::fs::read_to_string(&full_path) {
+            Ok(contents) => Ok(Some(contents)),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err).with_context(|| format!("failed to read {full_path:?}")),
+        }
+    }
+
+    pub fn write(
+        &mut self,
+        path: impl Into<PathBuf>,
+        contents: impl Into<String>,
+    ) -> anyhow::Result<()> {
+        self.write_noted(path, contents, None)
+    }
+
+    /// deletes `path`, if it exists
+    pub fn remove(&mut self, path: impl Into<PathBuf>) -> anyhow::Result<()> {
+        let path = path.into();
+        if let Some(previous) = self.previous(&path)? {
+            self.ops.push(Op {
+                path,
+                contents: None,
+                previous: Some(previous),
+                note: None,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// `write`, with a note on where the contents come from for `--dry-run`
+    pub fn write_noted(
+        &mut self,
+        path: impl Into<PathBuf>,
+        contents: impl Into<String>,
+        note: Option<String>,
+    ) -> anyhow::Result<()> {
+        let path = path.into();
+        let previous = self.previous(&path)?;
+        self.ops.push(Op {
+            path,
+            contents: Some(contents.into()),
+            previous,
+            note,
+        });
+
+        Ok(())
+    }
+
+    fn previous(&self, path: &Path) -> anyhow::Result<Option<Vec<u8>>> {
+        let full_path = self.root.join(path);
+        match std::fs::read(&full_path) {
+            Ok(previous) => Ok(Some(previous)),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err).with_context(|| format!("failed to read {full_path:?}")),
+        }
+    }
+
+    /// writes & deletes every planned file, restoring all of them if any of it fails
+    pub fn apply(self) -> anyhow::Result<()> {
+        let mut applied = Applied::default();
+        for op in &self.ops {
+            if op.action() == "keep" {
+                continue;
+            }
+            if let Err(err) = applied.write(&self.root, op) {
+                applied.roll_back(&self.root);
+                return Err(err.context("rolled back everything generated"));
+            }
+            info!("{} {}", op.action(), op.path.display());
+        }
+        // directories that deleting emptied go too, e.g. a day's examples
+        for op in self.ops.iter().filter(|op| op.contents.is_none()) {
+            if let Some(dir) = self.root.join(&op.path).parent() {
+                let _ = std::fs::remove_dir(dir);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for Plan {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for op in &self.ops {
+            write!(f, "{:<6} {}", op.action(), op.path.display())?;
+            if let Some(note) = &op.note {
+                write!(f, " ({note})")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// What has been written so far, newest last
+#[derive(Default)]
+struct Applied<'a> {
+    ops: Vec<&'a Op>,
+    /// directories that didn't exist before, outermost first
+    dirs: Vec<PathBuf>,
+}
+
+impl<'a> Applied<'a> {
+    fn write(&mut self, root: &Path, op: &'a Op) -> anyhow::Result<()> {
+        let path = root.join(&op.path);
+        let Some(contents) = &op.contents else {
+            std::fs::remove_file(&path).with_context(|| format!("failed to delete {path:?}"))?;
+            self.ops.push(op);
+            return Ok(());
+        };
+        if let Some(parent) = path.parent() {
+            let mut missing = parent
+                .ancestors()
+                .take_while(|dir| !dir.exists())
+                .map(Path::to_path_buf)
+                .collect::<Vec<_>>();
+            missing.reverse();
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("failed to create {parent:?}"))?;
+            self.dirs.extend(missing);
+        }
+        // recorded first, a partly written file is still put back
+        self.ops.push(op);
+        std::fs::write(&path, contents).with_context(|| format!("failed to write {path:?}"))
+    }
+
+    fn roll_back(self, root: &Path) {
+        for op in self.ops.into_iter().rev() {
+            let path = root.join(&op.path);
+            let restored = match &op.previous {
+                Some(previous) => std::fs::write(&path, previous),
+                None => std::fs::remove_file(&path),
+            };
+            if let Err(err) = restored {
+                warn!("failed to roll back {path:?}: {err}");
+            }
+        }
+        for dir in self.dirs.iter().rev() {
+            if let Err(err) = std::fs::remove_dir(dir) {
+                warn!("failed to remove {dir:?}: {err}");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{plan::Plan, testing::TempDir};
+
+    #[test]
+    fn describes_the_changes() {
+        let root = TempDir::new();
+        root.write("src/mod.rs", "pub mod day01;\n");
+        root.write("README.md", "# aoc\n");
+
+        let mut plan = Plan::new(&*root);
+        plan.write("src/day02.rs", "fn main() {}\n").unwrap();
+        plan.write("src/mod.rs", "pub mod day01;\npub mod day02;\n")
+            .unwrap();
+        plan.write("README.md", "# aoc\n").unwrap();
+        plan.write_noted("input/day02.txt", "", Some("downloaded".to_string()))
+            .unwrap();
+        assert_eq!(
+            "create src/day02.rs\n\
+            update src/mod.rs\n\
+            keep   README.md\n\
+            create input/day02.txt (downloaded)\n",
+            plan.to_string()
+        );
+    }
+
+    #[test]
+    fn rolls_back_on_failure() {
+        let root = TempDir::new();
+        root.write("src/mod.rs", "pub mod day01;\n");
+        root.write("src/day01.rs", "fn main() {}\n");
+
+        let mut plan = Plan::new(&*root);
+        plan.write("src/mod.rs", "pub mod day01;\npub mod day02;\n")
+            .unwrap();
+        plan.remove("src/day01.rs").unwrap();
+        plan.remove("src/day03.rs").unwrap();
+        plan.write("src/y2022/day02.rs", "fn main() {}\n").unwrap();
+        plan.write("input/2023/day02.txt", "1\n").unwrap();
+        // a file where a directory is needed makes the last write fail
+        root.write("input", "");
+        let err = plan.apply().unwrap_err();
+        assert_eq!("rolled back everything generated", err.to_string());
+
+        assert_eq!(
+            "pub mod day01;\n",
+            std::fs::read_to_string(root.join("src/mod.rs")).unwrap()
+        );
+        assert!(!root.join("src/y2022").exists());
+        assert_eq!(
+            "fn main() {}\n",
+            std::fs::read_to_string(root.join("src/day01.rs")).unwrap()
+        );
+
+        std::fs::remove_file(root.join("input")).unwrap();
+        let mut plan = Plan::new(&*root);
+        plan.write("input/2023/day02.txt", "1\n").unwrap();
+        plan.apply().unwrap();
+        assert_eq!(
+            "1\n",
+            std::fs::read_to_string(root.join("input/2023/day02.txt")).unwrap()
+        );
+    }
+}
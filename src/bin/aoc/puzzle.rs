@@ -0,0 +1,250 @@
+use anyhow::Context;
+
+/// A piece of html, tags keep their attributes unparsed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Token<'a> {
+    Text(&'a str),
+    Open { name: &'a str, attrs: &'a str },
+    Close(&'a str),
+}
+
+/// splits `html` into tags & text, comments and doctypes are dropped
+fn tokens(mut html: &str) -> impl Iterator<Item = Token<'_>> {
+    std::iter::from_fn(move || loop {
+        if html.is_empty() {
+            return None;
+        }
+        let Some(rest) = html.strip_prefix('<') else {
+            let end = html.find('<').unwrap_or(html.len());
+            let (text, rest) = html.split_at(end);
+            html = rest;
+            return Some(Token::Text(text));
+        };
+
+        if let Some(comment) = rest.strip_prefix("!--") {
+            html = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+            continue;
+        }
+        let end = rest.find('>').unwrap_or(rest.len());
+        let tag = rest[..end].trim_end_matches('/');
+        html = rest.get(end + 1..).unwrap_or("");
+
+        if tag.starts_with('!') {
+            continue;
+        }
+        if let Some(name) = tag.strip_prefix('/') {
+            return Some(Token::Close(name.trim()));
+        }
+        let (name, attrs) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+        return Some(Token::Open { name, attrs });
+    })
+}
+
+/// the value of `attr` in a tag's attributes, e.g. the `href` of a link
+fn attr<'a>(attrs: &'a str, attr: &str) -> Option<&'a str> {
+    let start = attrs.find(&format!("{attr}=\""))? + attr.len() + 2;
+    let end = start + attrs[start..].find('"')?;
+    Some(&attrs[start..end])
+}
+
+/// replaces the entities the puzzle pages use with the characters they stand for
+fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let entity = rest.find(';').map(|end| (&rest[1..end], end));
+        let c = entity.and_then(|(entity, _)| match entity {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            _ => {
+                let code = entity.strip_prefix('#')?;
+                let code = match code.strip_prefix(['x', 'X']) {
+                    Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                    None => code.parse().ok()?,
+                };
+                char::from_u32(code)
+            }
+        });
+        match (c, entity) {
+            (Some(c), Some((_, end))) => {
+                decoded.push(c);
+                rest = &rest[end + 1..];
+            }
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+/// the text of every `<pre><code>` block in the page, in order
+pub fn code_blocks(html: &str) -> Vec<String> {
+    let mut blocks = vec![];
+    let mut block = None::<String>;
+    let mut in_pre = false;
+
+    for token in tokens(html) {
+        match token {
+            Token::Open { name: "pre", .. } => in_pre = true,
+            Token::Close("pre") => in_pre = false,
+            Token::Open { name: "code", .. } if in_pre => block = Some(String::new()),
+            Token::Close("code") => blocks.extend(block.take()),
+            Token::Text(text) => {
+                if let Some(block) = &mut block {
+                    block.push_str(&decode_entities(text));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    blocks
+}
+
+/// the `index`th (1-based) `<pre><code>` block in the page
+pub fn example(html: &str, index: usize) -> anyhow::Result<String> {
+    let blocks = code_blocks(html);
+    let found = blocks.len();
+    index
+        .checked_sub(1)
+        .and_then(|i| blocks.into_iter().nth(i))
+        .with_context(|| format!("asked for example {index} but the page only has {found}"))
+}
+
+/// the puzzle descriptions in the page as markdown, one section per part
+pub fn to_markdown(html: &str) -> String {
+    let mut markdown = String::new();
+    let mut in_article = false;
+    let mut in_pre = false;
+    let mut in_code = false;
+    let mut links = vec![];
+
+    for token in tokens(html) {
+        match token {
+            Token::Open {
+                name: "article", ..
+            } => in_article = true,
+            Token::Close("article") => {
+                in_article = false;
+                markdown.truncate(markdown.trim_end().len());
+                markdown.push_str("\n\n");
+            }
+            _ if !in_article => {}
+            Token::Open { name: "h2", .. } => markdown.push_str("## "),
+            Token::Close("h2" | "p") => markdown.push_str("\n\n"),
+            Token::Open { name: "pre", .. } => {
+                in_pre = true;
+                markdown.push_str("```\n");
+            }
+            Token::Close("pre") => {
+                in_pre = false;
+                if !markdown.ends_with('\n') {
+                    markdown.push('\n');
+                }
+                markdown.push_str("```\n\n");
+            }
+            Token::Open { name: "code", .. } if !in_pre => {
+                in_code = true;
+                markdown.push('`');
+            }
+            Token::Close("code") if !in_pre => {
+                in_code = false;
+                markdown.push('`');
+            }
+            // emphasis can't be shown inside code, the backticks already stand out
+            Token::Open { name: "em", .. } | Token::Close("em") if !in_pre && !in_code => {
+                markdown.push_str("**");
+            }
+            Token::Open { name: "a", attrs } => {
+                links.push(attr(attrs, "href").unwrap_or_default());
+                markdown.push('[');
+            }
+            Token::Close("a") => {
+                let href = links.pop().unwrap_or_default();
+                markdown.push_str(&format!("]({href})"));
+            }
+            Token::Open { name: "li", .. } => markdown.push_str("- "),
+            Token::Close("li") => markdown.push('\n'),
+            Token::Close("ul") => markdown.push('\n'),
+            Token::Text(text) if in_pre => markdown.push_str(&decode_entities(text)),
+            // list items sit on their own lines in the source, which markdown doesn't need
+            Token::Text(text) if text.trim().is_empty() && markdown.ends_with('\n') => {}
+            Token::Text(text) => markdown.push_str(&decode_entities(text)),
+            _ => {}
+        }
+    }
+
+    markdown.truncate(markdown.trim_end().len());
+    markdown.push('\n');
+    markdown
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::puzzle::{code_blocks, decode_entities, example, to_markdown};
+
+    const DAY01: &str = include_str!("../../../tests/fixtures/puzzles/day01.html");
+    const DAY19: &str = include_str!("../../../tests/fixtures/puzzles/day19.html");
+
+    #[test]
+    fn extracts_examples() {
+        assert_eq!(2, code_blocks(DAY01).len());
+        assert_eq!(
+            "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n",
+            example(DAY01, 1).unwrap()
+        );
+        assert_eq!(
+            include_str!("../../../input/2023/day01_test.txt"),
+            example(DAY01, 2).unwrap()
+        );
+        assert_eq!(
+            "asked for example 3 but the page only has 2",
+            example(DAY01, 3).unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn decodes_entities_in_examples() {
+        assert_eq!(
+            include_str!("../../../input/2023/day19_test.txt"),
+            example(DAY19, 1).unwrap()
+        );
+        assert_eq!(
+            "a<b & c>d &bogus; 'e'",
+            decode_entities("a&lt;b &amp; c&gt;d &bogus; &#39;e&#x27;")
+        );
+    }
+
+    #[test]
+    fn converts_descriptions_to_markdown() {
+        let markdown = to_markdown(DAY01);
+        assert!(markdown.starts_with("## --- Day 1: Trebuchet?! ---\n\nSomething is wrong"));
+        assert!(markdown.contains("\n\n## --- Part Two ---\n\n"));
+        assert!(markdown.contains(
+            "use a [weather machine](/2023/day/1/weather-machine) (\"not powerful enough\")"
+        ));
+        assert!(markdown
+            .contains("For example:\n\n```\n1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n```\n\n"));
+        assert!(markdown.contains("produces `142`.\n\n"));
+        assert!(markdown.contains("**What is the sum of all of the calibration values?**\n"));
+        assert!(!markdown.contains("Your puzzle answer"));
+
+        let markdown = to_markdown(DAY19);
+        assert!(markdown
+            .contains("four categories:\n\n- `x`: E**x**tremely cool looking\n- `m`: **M**usical"));
+        assert!(markdown.contains(
+            "- `s`: **S**hiny\n\nConsider the workflow `ex{x>10:one,m<20:two,a>30:R,A}`."
+        ));
+        assert!(markdown.contains("```\npx{a<2006:qkq,m>2090:A,rfg}\n"));
+        assert!(!markdown.contains("identify yourself"));
+    }
+}
@@ -0,0 +1,149 @@
+use crate::PackageName;
+
+const SOLUTIONS_HEADING: &str = "## Solutions";
+const TABLE_HEADER: &str = "| Puzzle | Solution |\n| --- | --- |";
+
+fn solution_row(pkg_name: PackageName, year: usize) -> String {
+    format!(
+        "| [Day {day}](https://adventofcode.com/{year}/day/{day}) | [{year}/{pkg_name}](src/y{year}/{pkg_name}.rs) |",
+        day = pkg_name.0
+    )
+}
+
+/// the year & day a row links to, rows that don't link to a solver aren't ordered
+fn row_day(row: &str) -> Option<(usize, u32)> {
+    let start = row.find("(src/y")? + "(src/y".len();
+    let end = start + row[start..].find(".rs)")?;
+    let (year, day) = row[start..end].split_once("/day")?;
+    Some((year.parse().ok()?, day.parse().ok()?))
+}
+
+/// `readme` with a row for `pkg_name` in year & day order, the section is appended if missing
+pub fn add_solution_row(readme: &str, pkg_name: PackageName, year: usize) -> String {
+    let row = solution_row(pkg_name, year);
+    let key = (year, pkg_name.0);
+    let mut lines = readme.lines().collect::<Vec<_>>();
+
+    let Some(heading) = lines.iter().position(|&line| line == SOLUTIONS_HEADING) else {
+        let mut readme = readme.trim_end().to_string();
+        readme.push_str(&format!(
+            "\n\n{SOLUTIONS_HEADING}\n\n{TABLE_HEADER}\n{row}\n"
+        ));
+        return readme;
+    };
+
+    // the table's rows follow its header & separator
+    let table_start = (heading + 1..lines.len())
+        .find(|&i| lines[i].starts_with('|'))
+        .unwrap_or(lines.len());
+    let rows_start = (table_start + 2).min(lines.len());
+    let rows_end = (rows_start..lines.len())
+        .find(|&i| !lines[i].starts_with('|'))
+        .unwrap_or(lines.len());
+
+    let rows = &lines[rows_start..rows_end];
+    if rows.iter().any(|&existing| row_day(existing) == Some(key)) {
+        return readme.to_string();
+    }
+    let insert_at = rows
+        .iter()
+        .position(|&existing| row_day(existing).is_some_and(|day| day > key))
+        .map_or(rows_end, |i| rows_start + i);
+    lines.insert(insert_at, &row);
+
+    let mut updated = lines.join("\n");
+    if readme.ends_with('\n') {
+        updated.push('\n');
+    }
+    updated
+}
+
+/// `readme` without the row for `pkg_name`
+pub fn remove_solution_row(readme: &str, pkg_name: PackageName, year: usize) -> String {
+    let key = (year, pkg_name.0);
+    let mut updated = readme
+        .lines()
+        .filter(|&line| !line.starts_with('|') || row_day(line) != Some(key))
+        .collect::<Vec<_>>()
+        .join("\n");
+    if readme.ends_with('\n') {
+        updated.push('\n');
+    }
+    updated
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        readme::{add_solution_row, remove_solution_row},
+        PackageName,
+    };
+
+    const README: &str = "\
+# Advent of Code 2023
+
+## Solutions
+
+| Puzzle | Solution |
+| --- | --- |
+| [Day 1](https://adventofcode.com/2023/day/1) | [2023/day01](src/y2023/day01.rs) |
+| [Day 5](https://adventofcode.com/2023/day/5) | [2023/day05](src/y2023/day05.rs) |
+
+## Benchmarks
+";
+
+    #[test]
+    fn inserts_rows_in_day_order() {
+        let updated = add_solution_row(README, PackageName(3), 2023);
+        assert!(
+            updated.contains(
+                "(src/y2023/day01.rs) |\n\
+            | [Day 3](https://adventofcode.com/2023/day/3) | [2023/day03](src/y2023/day03.rs) |\n\
+            | [Day 5]"
+            ),
+            "{updated}"
+        );
+
+        let updated = add_solution_row(&updated, PackageName(12), 2023);
+        assert!(
+            updated.contains("(src/y2023/day12.rs) |\n\n## Benchmarks\n"),
+            "{updated}"
+        );
+
+        // earlier years sort first whatever their day
+        let updated = add_solution_row(&updated, PackageName(25), 2022);
+        assert!(
+            updated.contains(
+                "| --- | --- |\n\
+                | [Day 25](https://adventofcode.com/2022/day/25) | [2022/day25](src/y2022/day25.rs) |\n\
+                | [Day 1](https://adventofcode.com/2023/day/1)"
+            ),
+            "{updated}"
+        );
+    }
+
+    #[test]
+    fn is_idempotent() {
+        let once = add_solution_row(README, PackageName(7), 2023);
+        assert_eq!(once, add_solution_row(&once, PackageName(7), 2023));
+        assert_eq!(README, add_solution_row(README, PackageName(5), 2023));
+        assert_ne!(README, add_solution_row(README, PackageName(5), 2022));
+    }
+
+    #[test]
+    fn adds_a_missing_section() {
+        let updated = add_solution_row("# Advent of Code\n", PackageName(1), 2023);
+        assert_eq!(
+            "# Advent of Code\n\n## Solutions\n\n| Puzzle | Solution |\n| --- | --- |\n\
+            | [Day 1](https://adventofcode.com/2023/day/1) | [2023/day01](src/y2023/day01.rs) |\n",
+            updated
+        );
+    }
+
+    #[test]
+    fn removes_rows() {
+        let updated = add_solution_row(README, PackageName(3), 2023);
+        assert_eq!(README, remove_solution_row(&updated, PackageName(3), 2023));
+        assert_eq!(README, remove_solution_row(README, PackageName(5), 2022));
+    }
+}
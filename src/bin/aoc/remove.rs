@@ -0,0 +1,133 @@
+use std::{io::ErrorKind, path::Path};
+
+use anyhow::Context;
+use clap::Parser;
+
+use crate::{modfile, plan::Plan, readme, PackageName};
+
+#[derive(Parser, Debug)]
+pub struct RemoveArgs {
+    #[arg(value_parser = clap::value_parser!(u32).range(1..=25))]
+    day: u32,
+    #[arg(short, long, default_value_t = 2023)]
+    year: usize,
+    /// print the files that would be deleted or updated without touching them
+    #[arg(long, default_value_t = false)]
+    dry_run: bool,
+}
+
+/// deletes everything `aoc` generated for a day & unregisters it, recorded answers are kept
+pub fn run(root: &Path, args: RemoveArgs) -> anyhow::Result<()> {
+    let RemoveArgs { day, year, dry_run } = args;
+    let pkg_name = PackageName(day);
+    let plan = plan_removal(root, year, pkg_name)?;
+
+    if dry_run {
+        print!("{plan}");
+        return Ok(());
+    }
+    plan.apply()
+}
+
+fn plan_removal(root: &Path, year: usize, pkg_name: PackageName) -> anyhow::Result<Plan> {
+    let mut plan = Plan::new(root);
+
+    let mod_path = format!("src/y{year}/mod.rs");
+    let mod_file = plan
+        .read(&mod_path)?
+        .with_context(|| format!("{year} has no solvers, {mod_path} is missing"))?;
+    let mod_file = modfile::remove_day(&mod_file, pkg_name)
+        .with_context(|| format!("{year} {pkg_name} isn't registered in {mod_path}"))?;
+    plan.write(mod_path, mod_file)?;
+
+    plan.remove(format!("src/bin/y{year}_{pkg_name}.rs"))?;
+    plan.remove(format!("src/y{year}/{pkg_name}.rs"))?;
+    if let Some(readme) = plan.read("README.md")? {
+        plan.write(
+            "README.md",
+            readme::remove_solution_row(&readme, pkg_name, year),
+        )?;
+    }
+
+    for input in [format!("{pkg_name}"), format!("{pkg_name}_test")] {
+        plan.remove(format!("input/{year}/{input}.txt"))?;
+        plan.remove(format!("input/{year}/{input}.toml"))?;
+    }
+    let examples = format!("input/{year}/{pkg_name}");
+    match std::fs::read_dir(root.join(&examples)) {
+        Ok(entries) => {
+            let mut names = entries
+                .map(|entry| Ok(entry?.file_name()))
+                .collect::<std::io::Result<Vec<_>>>()
+                .with_context(|| format!("failed to list {examples}"))?;
+            names.sort();
+            for name in names {
+                plan.remove(Path::new(&examples).join(name))?;
+            }
+        }
+        Err(err) if err.kind() == ErrorKind::NotFound => {}
+        Err(err) => return Err(err).with_context(|| format!("failed to list {examples}")),
+    }
+    plan.remove(format!("puzzles/{year}/{pkg_name}.md"))?;
+
+    Ok(plan)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{remove::plan_removal, testing::TempDir, PackageName};
+
+    fn workspace() -> TempDir {
+        let root = TempDir::new();
+        let files = [
+            (
+                "src/y2023/mod.rs",
+                "pub mod day01;\npub mod day02;\nmod helpers;\n\n\
+                pub const SOLVERS: &[Solver] = &[\n    \
+                solver!(day01, Day01),\n    solver!(day02, Day02),\n];\n",
+            ),
+            ("src/y2023/day02.rs", ""),
+            ("src/bin/y2023_day02.rs", ""),
+            ("input/2023/day02.txt", "1\n"),
+            ("input/2023/day02_test.txt", "2\n"),
+            ("input/2023/day02/example_1.txt", "3\n"),
+            ("input/2023/day02/example_1.toml", "[answers]\n"),
+        ];
+        for (path, contents) in files {
+            root.write(path, contents);
+        }
+        root
+    }
+
+    #[test]
+    fn removes_a_day() {
+        let root = workspace();
+        let plan = plan_removal(&root, 2023, PackageName(2)).unwrap();
+        assert_eq!(
+            "update src/y2023/mod.rs\n\
+            delete src/bin/y2023_day02.rs\n\
+            delete src/y2023/day02.rs\n\
+            delete input/2023/day02.txt\n\
+            delete input/2023/day02_test.txt\n\
+            delete input/2023/day02/example_1.toml\n\
+            delete input/2023/day02/example_1.txt\n",
+            plan.to_string()
+        );
+        plan.apply().unwrap();
+
+        assert_eq!(
+            "pub mod day01;\nmod helpers;\n\n\
+            pub const SOLVERS: &[Solver] = &[\n    solver!(day01, Day01),\n];\n",
+            std::fs::read_to_string(root.join("src/y2023/mod.rs")).unwrap()
+        );
+        assert!(!root.join("src/y2023/day02.rs").exists());
+        assert!(!root.join("input/2023/day02").exists());
+        assert!(root.join("input/2023").exists());
+
+        let err = plan_removal(&root, 2023, PackageName(2)).err().unwrap();
+        assert_eq!(
+            "2023 day02 isn't registered in src/y2023/mod.rs",
+            err.to_string()
+        );
+    }
+}
@@ -0,0 +1,232 @@
+use std::{fmt::Write, io::ErrorKind, path::Path};
+
+use advent_of_code_2023::answers::{AnswerBook, ANSWERS_FILE};
+use anyhow::Context;
+use clap::Parser;
+
+use crate::{
+    modfile,
+    unlock::{self, Clock},
+    PackageName,
+};
+
+#[derive(Parser, Debug)]
+pub struct StatusArgs {
+    #[arg(short, long, default_value_t = 2023)]
+    year: usize,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum SolverState {
+    Missing,
+    /// missing, but the puzzle isn't out yet either
+    Locked,
+    /// both parts still return `()`
+    Stub,
+    /// part 2 still returns `()`
+    Part1,
+    Done,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum InputState {
+    Missing,
+    Empty,
+    Present,
+}
+
+/// What's on disk for one day of the calendar
+#[derive(Debug)]
+struct DayStatus {
+    day: u32,
+    solver: SolverState,
+    input: InputState,
+    test: InputState,
+    /// how many parts of the real input have an answer in `answers.toml`
+    recorded: usize,
+    /// registered & with a real input, which is what the benches run
+    benched: bool,
+}
+
+/// prints a row per day of `year` showing how far along it is
+pub fn run(root: &Path, args: StatusArgs, clock: &dyn Clock) -> anyhow::Result<()> {
+    let statuses = scan(root, args.year, clock)?;
+    print!("{}", render(&statuses)?);
+    Ok(())
+}
+
+fn read(path: &Path) -> anyhow::Result<Option<String>> {
+    match std::fs::read_to_string(path) {
+        Ok(contents) => Ok(Some(contents)),
+        Err(err) if err.kind() == ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err).with_context(|| format!("failed to read {path:?}")),
+    }
+}
+
+fn input_state(path: &Path) -> anyhow::Result<InputState> {
+    Ok(match read(path)? {
+        None => InputState::Missing,
+        Some(input) if input.trim().is_empty() => InputState::Empty,
+        Some(_) => InputState::Present,
+    })
+}
+
+/// a part is a stub until its type is something other than the template's `()`
+fn solver_state(solver: &str) -> SolverState {
+    let is_stub = |part: u8| {
+        let stub = format!("type Part{part} = ();");
+        solver.lines().any(|line| line.trim() == stub)
+    };
+    match (is_stub(1), is_stub(2)) {
+        (true, _) => SolverState::Stub,
+        (false, true) => SolverState::Part1,
+        (false, false) => SolverState::Done,
+    }
+}
+
+fn scan(root: &Path, year: usize, clock: &dyn Clock) -> anyhow::Result<Vec<DayStatus>> {
+    let registered = read(&root.join(format!("src/y{year}/mod.rs")))?
+        .map(|mod_file| modfile::registered_days(&mod_file))
+        .unwrap_or_default();
+    let answers = AnswerBook::load(root.join(ANSWERS_FILE))?;
+
+    (1..=25)
+        .map(|day| {
+            let pkg_name = PackageName(day);
+            let solver = read(&root.join(format!("src/y{year}/{pkg_name}.rs")))?;
+            let input = input_state(&root.join(format!("input/{year}/{pkg_name}.txt")))?;
+            let test = input_state(&root.join(format!("input/{year}/{pkg_name}_test.txt")))?;
+            let state = match &solver {
+                Some(solver) => solver_state(solver),
+                None if unlock::time_until_unlock(clock, year, day)?.is_some() => {
+                    SolverState::Locked
+                }
+                None => SolverState::Missing,
+            };
+
+            let recorded = answers
+                .get(year as u32, &pkg_name.to_string(), "real")
+                .map_or(0, |recorded| {
+                    usize::from(recorded.part1.is_some()) + usize::from(recorded.part2.is_some())
+                });
+
+            Ok(DayStatus {
+                day,
+                solver: state,
+                input,
+                test,
+                recorded,
+                benched: registered.contains(&pkg_name) && input == InputState::Present,
+            })
+        })
+        .collect()
+}
+
+fn render(statuses: &[DayStatus]) -> anyhow::Result<String> {
+    let input = |state| match state {
+        InputState::Missing => "-",
+        InputState::Empty => "empty",
+        InputState::Present => "ok",
+    };
+
+    let mut out = String::new();
+    writeln!(out, "day  solver  input  test   answers  benched")?;
+    for status in statuses {
+        let solver = match status.solver {
+            SolverState::Missing => "-",
+            SolverState::Locked => "locked",
+            SolverState::Stub => "stub",
+            SolverState::Part1 => "part 1",
+            SolverState::Done => "done",
+        };
+        let recorded = format!("{}/2", status.recorded);
+        writeln!(
+            out,
+            "{day:>3}  {solver:<6}  {input:<5}  {test:<5}  {recorded:<7}  {benched}",
+            day = status.day,
+            input = input(status.input),
+            test = input(status.test),
+            benched = if status.benched { "yes" } else { "no" },
+        )?;
+    }
+
+    let count = |state| {
+        statuses
+            .iter()
+            .filter(|status| status.solver == state)
+            .count()
+    };
+    writeln!(
+        out,
+        "\n{} done, {} with part 2 left, {} stubs, {} missing",
+        count(SolverState::Done),
+        count(SolverState::Part1),
+        count(SolverState::Stub),
+        count(SolverState::Missing)
+    )?;
+
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        status::{render, scan, solver_state, SolverState},
+        testing::TempDir,
+        unlock::SystemClock,
+    };
+
+    const STUB: &str = include_str!("../../../templates/solver.rs.tmpl");
+
+    #[test]
+    fn reads_solvers() {
+        assert_eq!(SolverState::Stub, solver_state(STUB));
+        let part1 = STUB.replace("type Part1 = ();", "type Part1 = usize;");
+        assert_eq!(SolverState::Part1, solver_state(&part1));
+        let done = part1.replace("type Part2 = ();", "type Part2 = String;");
+        assert_eq!(SolverState::Done, solver_state(&done));
+    }
+
+    #[test]
+    fn shows_every_day() {
+        let root = TempDir::new();
+        let files = [
+            (
+                "src/y2022/mod.rs",
+                "pub mod day01;\npub mod day02;\n\npub const SOLVERS: &[Solver] = &[\n    \
+                solver!(day01, Day01),\n    solver!(day02, Day02),\n];\n",
+            ),
+            (
+                "src/y2022/day01.rs",
+                "type Part1 = u32;\ntype Part2 = u32;\n",
+            ),
+            ("src/y2022/day02.rs", STUB),
+            ("input/2022/day01.txt", "1\n"),
+            ("input/2022/day01_test.txt", "2\n"),
+            ("input/2022/day02.txt", ""),
+            (
+                "answers.toml",
+                "[2022.day01.real]\npart1 = 1\npart2 = 2\n\n[2022.day01.test]\npart1 = 3\n",
+            ),
+        ];
+        for (path, contents) in files {
+            root.write(path, contents);
+        }
+
+        let out = render(&scan(&root, 2022, &SystemClock).unwrap()).unwrap();
+        let lines = out.lines().collect::<Vec<_>>();
+        assert_eq!(
+            [
+                "day  solver  input  test   answers  benched",
+                "  1  done    ok     ok     2/2      yes",
+                "  2  stub    empty  -      0/2      no",
+                "  3  -       -      -      0/2      no",
+            ],
+            lines[..4]
+        );
+        assert_eq!(
+            Some(&"1 done, 0 with part 2 left, 1 stubs, 23 missing"),
+            lines.last()
+        );
+    }
+}
@@ -0,0 +1,475 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{Display, Formatter},
+    io::ErrorKind,
+    path::Path,
+    time::Duration,
+};
+
+use advent_of_code_2023::{input_path, read_context, read_input, Solver};
+use anyhow::{bail, Context};
+use chrono::{DateTime, Utc};
+use clap::Parser;
+use serde::{Deserialize, Serialize};
+
+use crate::client::AocClient;
+
+pub const SUBMISSIONS_FILE: &str = "submissions.toml";
+
+#[derive(Parser, Debug)]
+pub struct SubmitArgs {
+    day: u32,
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+    #[arg(short, long, default_value_t = 2023)]
+    year: usize,
+}
+
+/// solves `day` on its real input and submits the answer for `part`, unless the
+/// submission history already shows it to be wrong
+pub fn run(root: &Path, args: SubmitArgs) -> anyhow::Result<()> {
+    let SubmitArgs { day, part, year } = args;
+    let solver = u32::try_from(year)
+        .ok()
+        .and_then(|year| Solver::find(year, day))
+        .with_context(|| format!("{year} day {day} is not registered"))?;
+    let path = root.join(input_path(solver.year, solver.name, false));
+    let input = read_input(&path)?;
+    let ctx = read_context(&path)?;
+    let solution = (solver.solve)(&input, &ctx)?;
+    let answer = match part {
+        1 => solution.part1,
+        _ => solution.part2,
+    }
+    .with_context(|| format!("{} part {part} failed", solver.label()))?
+    .with_context(|| format!("{} part {part} isn't solved yet", solver.label()))?
+    .text()?;
+
+    let client = AocClient::from_env()?;
+    let history_path = root.join(SUBMISSIONS_FILE);
+    let mut history = SubmissionHistory::load(&history_path)?;
+    let outcome = submit(&client, &mut history, year, day, part, &answer, Utc::now())?;
+    history.save(&history_path)?;
+    println!("{} part {part}: {answer} is {outcome}", solver.label());
+
+    Ok(())
+}
+
+/// submits `answer` and records the outcome in `history`, refusing answers it already rules out
+pub fn submit(
+    client: &AocClient,
+    history: &mut SubmissionHistory,
+    year: usize,
+    day: u32,
+    part: u8,
+    answer: &str,
+    now: DateTime<Utc>,
+) -> anyhow::Result<Outcome> {
+    let attempts = history.attempts(year, day, part);
+    if let Some(refusal) = Refusal::check(attempts, answer, now) {
+        bail!("not submitting {answer}: {refusal}");
+    }
+
+    let page = client.submit(year, day, part, answer)?;
+    let outcome = parse_response(&page)?;
+    history.record(
+        year,
+        day,
+        part,
+        Attempt {
+            answer: answer.to_string(),
+            outcome,
+            submitted_at: now,
+        },
+    );
+
+    Ok(outcome)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "outcome", rename_all = "kebab-case")]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    RateLimited { wait_secs: u64 },
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::TooHigh => write!(f, "too high"),
+            Outcome::TooLow => write!(f, "too low"),
+            Outcome::Wrong => write!(f, "wrong"),
+            Outcome::RateLimited { wait_secs } => {
+                write!(f, "rate limited, wait {wait_secs}s before trying again")
+            }
+        }
+    }
+}
+
+/// reads the outcome out of the page returned for a submission
+pub fn parse_response(page: &str) -> anyhow::Result<Outcome> {
+    let text = article_text(page);
+
+    if text.contains("That's the right answer") {
+        Ok(Outcome::Correct)
+    } else if text.contains("That's not the right answer") {
+        if text.contains("your answer is too high") {
+            Ok(Outcome::TooHigh)
+        } else if text.contains("your answer is too low") {
+            Ok(Outcome::TooLow)
+        } else {
+            Ok(Outcome::Wrong)
+        }
+    } else if text.contains("You gave an answer too recently") {
+        let wait = parse_wait(&text).context("failed to find how long to wait")?;
+        Ok(Outcome::RateLimited {
+            wait_secs: wait.as_secs(),
+        })
+    } else if text.contains("You don't seem to be solving the right level") {
+        bail!("this part is locked or already solved")
+    } else {
+        bail!("unrecognised response: {text}")
+    }
+}
+
+/// the text of the page's `<article>`, or the whole page if there isn't one
+fn article_text(page: &str) -> String {
+    let article = page
+        .find("<article")
+        .and_then(|start| {
+            let end = page[start..].find("</article>")?;
+            Some(&page[start..start + end])
+        })
+        .unwrap_or(page);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// parses the `1m 23s` out of `You have 1m 23s left to wait`
+fn parse_wait(text: &str) -> Option<Duration> {
+    let start = text.find("You have ")? + "You have ".len();
+    let end = start + text[start..].find(" left to wait")?;
+
+    let mut secs = 0;
+    for part in text[start..end].split_whitespace() {
+        let unit = match part.chars().last()? {
+            'h' => 60 * 60,
+            'm' => 60,
+            's' => 1,
+            _ => return None,
+        };
+        let amount: u64 = part[..part.len() - 1].parse().ok()?;
+        secs += amount * unit;
+    }
+
+    Some(Duration::from_secs(secs))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub answer: String,
+    #[serde(flatten)]
+    pub outcome: Outcome,
+    pub submitted_at: DateTime<Utc>,
+}
+
+/// Every submission made, keyed by year, day and part e.g. `[[2023.day05.part1]]`
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct SubmissionHistory {
+    years: BTreeMap<String, BTreeMap<String, BTreeMap<String, Vec<Attempt>>>>,
+}
+
+impl SubmissionHistory {
+    pub fn load(path: impl AsRef<Path>) -> anyhow::Result<SubmissionHistory> {
+        let path = path.as_ref();
+        match std::fs::read_to_string(path) {
+            Ok(contents) => toml::from_str(&contents)
+                .with_context(|| format!("failed to parse submissions file {path:?}")),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(SubmissionHistory::default()),
+            Err(err) => {
+                Err(err).with_context(|| format!("failed to read submissions file {path:?}"))
+            }
+        }
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> anyhow::Result<()> {
+        let path = path.as_ref();
+        let contents = toml::to_string_pretty(self).context("failed to serialise submissions")?;
+        std::fs::write(path, contents)
+            .with_context(|| format!("failed to write submissions file {path:?}"))
+    }
+
+    pub fn attempts(&self, year: usize, day: u32, part: u8) -> &[Attempt] {
+        self.years
+            .get(&year.to_string())
+            .and_then(|days| days.get(&format!("day{day:0>2}")))
+            .and_then(|parts| parts.get(&format!("part{part}")))
+            .map_or(&[], Vec::as_slice)
+    }
+
+    pub fn record(&mut self, year: usize, day: u32, part: u8, attempt: Attempt) {
+        self.years
+            .entry(year.to_string())
+            .or_default()
+            .entry(format!("day{day:0>2}"))
+            .or_default()
+            .entry(format!("part{part}"))
+            .or_default()
+            .push(attempt);
+    }
+}
+
+/// Why an answer isn't worth submitting given the earlier attempts
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadySolved { answer: String },
+    KnownWrong { outcome: Outcome },
+    TooHigh { bound: String },
+    TooLow { bound: String },
+    RateLimited { remaining: Duration },
+}
+
+impl Refusal {
+    pub fn check(attempts: &[Attempt], answer: &str, now: DateTime<Utc>) -> Option<Refusal> {
+        let numeric = answer.parse::<i128>().ok();
+
+        for attempt in attempts {
+            let known = attempt.answer.parse::<i128>().ok();
+            let refusal = match attempt.outcome {
+                Outcome::Correct => Refusal::AlreadySolved {
+                    answer: attempt.answer.clone(),
+                },
+                Outcome::RateLimited { .. } => continue,
+                outcome if attempt.answer == answer => Refusal::KnownWrong { outcome },
+                Outcome::TooHigh if matches!((numeric, known), (Some(n), Some(k)) if n >= k) => {
+                    Refusal::TooHigh {
+                        bound: attempt.answer.clone(),
+                    }
+                }
+                Outcome::TooLow if matches!((numeric, known), (Some(n), Some(k)) if n <= k) => {
+                    Refusal::TooLow {
+                        bound: attempt.answer.clone(),
+                    }
+                }
+                _ => continue,
+            };
+            return Some(refusal);
+        }
+
+        // only the latest attempt says whether we're still waiting
+        let last = attempts.last()?;
+        let Outcome::RateLimited { wait_secs } = last.outcome else {
+            return None;
+        };
+        let retry_at = last.submitted_at + chrono::Duration::seconds(wait_secs as i64);
+        let remaining = (retry_at - now).to_std().ok()?;
+        (!remaining.is_zero()).then_some(Refusal::RateLimited { remaining })
+    }
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::AlreadySolved { answer } => write!(f, "already solved with {answer}"),
+            Refusal::KnownWrong { outcome } => write!(f, "already submitted and was {outcome}"),
+            Refusal::TooHigh { bound } => write!(f, "{bound} was already too high"),
+            Refusal::TooLow { bound } => write!(f, "{bound} was already too low"),
+            Refusal::RateLimited { remaining } => {
+                write!(f, "rate limited for another {}s", remaining.as_secs())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread::JoinHandle,
+        time::Duration,
+    };
+
+    use chrono::{DateTime, TimeZone, Utc};
+
+    use crate::{
+        client::AocClient,
+        submit::{parse_response, submit, Attempt, Outcome, Refusal, SubmissionHistory},
+    };
+
+    const TOO_HIGH: &str = "<html><body><main><article><p>That's not the right answer; \
+        your answer is too high.  If you're stuck, make sure you're using the full input data; \
+        Please wait one minute before trying again. \
+        <a href=\"/2023/day/5\">[Return to Day 5]</a></p></article></main></body></html>";
+
+    const RATE_LIMITED: &str = "<main><article><p>You gave an answer too recently; you have \
+        to wait after submitting an answer before trying again.  You have 1m 23s left to wait. \
+        <a href=\"/2023/day/5\">[Return to Day 5]</a></p></article></main>";
+
+    fn at(secs: i64) -> DateTime<Utc> {
+        Utc.timestamp_opt(1_701_752_400 + secs, 0).unwrap()
+    }
+
+    fn attempt(answer: &str, outcome: Outcome) -> Attempt {
+        Attempt {
+            answer: answer.to_string(),
+            outcome,
+            submitted_at: at(0),
+        }
+    }
+
+    /// answers a single request with `page`, handing back the raw request it received
+    fn stub_server(page: &'static str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request = String::new();
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(len) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                    content_length = len.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" {
+                    break;
+                }
+            }
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).unwrap();
+            request.push_str(std::str::from_utf8(&body).unwrap());
+
+            write!(
+                reader.get_mut(),
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{page}",
+                page.len()
+            )
+            .unwrap();
+            request
+        });
+        (base_url, handle)
+    }
+
+    #[test]
+    fn parses_responses() {
+        assert_eq!(Outcome::TooHigh, parse_response(TOO_HIGH).unwrap());
+        assert_eq!(
+            Outcome::RateLimited { wait_secs: 83 },
+            parse_response(RATE_LIMITED).unwrap()
+        );
+        assert_eq!(
+            Outcome::Correct,
+            parse_response("<article><p>That's the right answer!  You are one gold star closer to restoring snow operations.</p></article>").unwrap()
+        );
+        assert_eq!(
+            Outcome::Wrong,
+            parse_response("<article><p>That's not the right answer.  If you're stuck, make sure you're using the full input data</p></article>").unwrap()
+        );
+        assert!(parse_response("<article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article>").is_err());
+    }
+
+    #[test]
+    fn refuses_answers_ruled_out_by_history() {
+        let attempts = [
+            attempt("100", Outcome::TooHigh),
+            attempt("20", Outcome::TooLow),
+            attempt("abc", Outcome::Wrong),
+        ];
+        assert_eq!(None, Refusal::check(&attempts, "50", at(60)));
+        assert_eq!(
+            Some(Refusal::TooHigh {
+                bound: "100".to_string()
+            }),
+            Refusal::check(&attempts, "150", at(60))
+        );
+        assert_eq!(
+            Some(Refusal::TooLow {
+                bound: "20".to_string()
+            }),
+            Refusal::check(&attempts, "15", at(60))
+        );
+        assert_eq!(
+            Some(Refusal::KnownWrong {
+                outcome: Outcome::Wrong
+            }),
+            Refusal::check(&attempts, "abc", at(60))
+        );
+
+        let attempts = [attempt("42", Outcome::Correct)];
+        assert_eq!(
+            Some(Refusal::AlreadySolved {
+                answer: "42".to_string()
+            }),
+            Refusal::check(&attempts, "43", at(60))
+        );
+
+        let attempts = [attempt("42", Outcome::RateLimited { wait_secs: 30 })];
+        assert_eq!(
+            Some(Refusal::RateLimited {
+                remaining: Duration::from_secs(20)
+            }),
+            Refusal::check(&attempts, "42", at(10))
+        );
+        assert_eq!(None, Refusal::check(&attempts, "42", at(30)));
+    }
+
+    #[test]
+    fn history_round_trips_through_toml() {
+        let mut history = SubmissionHistory::default();
+        history.record(2023, 5, 1, attempt("100", Outcome::TooHigh));
+        history.record(
+            2023,
+            5,
+            1,
+            attempt("99", Outcome::RateLimited { wait_secs: 83 }),
+        );
+
+        let toml = toml::to_string_pretty(&history).unwrap();
+        assert!(toml.contains("[[2023.day05.part1]]"), "{toml}");
+        let history: SubmissionHistory = toml::from_str(&toml).unwrap();
+        assert_eq!(
+            Outcome::RateLimited { wait_secs: 83 },
+            history.attempts(2023, 5, 1)[1].outcome
+        );
+    }
+
+    #[test]
+    fn submits_to_stub_server_and_records_outcome() {
+        let (base_url, server) = stub_server(TOO_HIGH);
+        let client = AocClient::new(base_url, "cookie").unwrap();
+        let mut history = SubmissionHistory::default();
+
+        let outcome = submit(&client, &mut history, 2023, 5, 1, "100", at(0)).unwrap();
+        assert_eq!(Outcome::TooHigh, outcome);
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /2023/day/5/answer "), "{request}");
+        assert!(request.contains("session=cookie"), "{request}");
+        assert!(request.ends_with("level=1&answer=100"), "{request}");
+
+        // the stub has shut down, so this only passes if nothing is sent
+        let err = submit(&client, &mut history, 2023, 5, 1, "101", at(120)).unwrap_err();
+        assert_eq!(
+            "not submitting 101: 100 was already too high",
+            err.to_string()
+        );
+        assert_eq!(1, history.attempts(2023, 5, 1).len());
+    }
+}
@@ -0,0 +1,152 @@
+use std::{io::ErrorKind, path::Path};
+
+use anyhow::{bail, Context};
+use tracing::info;
+
+/// used when the workspace has no `templates/solver.rs.tmpl` of its own
+const BUILTIN_TEMPLATE: &str = include_str!("../../../templates/solver.rs.tmpl");
+const DEFAULT_TEMPLATE: &str = "solver";
+
+/// The values substituted for `{{name}}` placeholders in a solver template
+pub struct TemplateVars {
+    pub day: u32,
+    pub year: usize,
+    /// the module name, e.g. `day05`
+    pub pkg_name: String,
+    /// the solver struct, e.g. `Day05`
+    pub solution: String,
+}
+
+impl TemplateVars {
+    fn get(&self, name: &str) -> Option<String> {
+        Some(match name {
+            "day" => self.day.to_string(),
+            "year" => self.year.to_string(),
+            "pkg_name" => self.pkg_name.clone(),
+            "solution" => self.solution.clone(),
+            // solvers used to take an `is_test` flag, the test variant now differs by input & params
+            "input" => format!("{}.txt", self.pkg_name),
+            "test_input" => format!("{}_test.txt", self.pkg_name),
+            "test_params" => format!("{}_test.toml", self.pkg_name),
+            _ => return None,
+        })
+    }
+}
+
+/// `templates/{name}.rs.tmpl` in the workspace
+///
+/// Without a name the workspace's `solver` template is used, falling back to the
+/// built-in one if the workspace doesn't have it.
+pub fn load(root: &Path, name: Option<&str>) -> anyhow::Result<String> {
+    let path = root
+        .join("templates")
+        .join(format!("{}.rs.tmpl", name.unwrap_or(DEFAULT_TEMPLATE)));
+    match std::fs::read_to_string(&path) {
+        Ok(template) => {
+            info!("using template {path:?}");
+            Ok(template)
+        }
+        Err(err) if err.kind() == ErrorKind::NotFound && name.is_none() => {
+            info!("using built-in template");
+            Ok(BUILTIN_TEMPLATE.to_string())
+        }
+        Err(err) => Err(err).with_context(|| format!("failed to read template {path:?}")),
+    }
+}
+
+/// replaces every `{{name}}` in `template`, any other `{{` is left alone
+pub fn render(template: &str, vars: &TemplateVars) -> anyhow::Result<String> {
+    let mut rendered = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find("{{") {
+        rendered.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let placeholder = rest[2..].find("}}").map(|end| &rest[2..2 + end]);
+        match placeholder {
+            Some(name) if is_placeholder_name(name) => {
+                let Some(value) = vars.get(name) else {
+                    bail!("unknown template placeholder {{{{{name}}}}}");
+                };
+                rendered.push_str(&value);
+                rest = &rest[name.len() + 4..];
+            }
+            _ => {
+                rendered.push_str("{{");
+                rest = &rest[2..];
+            }
+        }
+    }
+    rendered.push_str(rest);
+
+    Ok(rendered)
+}
+
+/// whether `template` reads the test input's params, so the generator has to write them
+pub fn uses_test_params(template: &str) -> bool {
+    template.contains("{{test_params}}")
+}
+
+fn is_placeholder_name(name: &str) -> bool {
+    !name.is_empty() && name.bytes().all(|b| b.is_ascii_lowercase() || b == b'_')
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::template::{load, render, uses_test_params, TemplateVars};
+
+    fn vars() -> TemplateVars {
+        TemplateVars {
+            day: 12,
+            year: 2023,
+            pkg_name: "day12".to_string(),
+            solution: "Day12".to_string(),
+        }
+    }
+
+    #[test]
+    fn replaces_placeholders() {
+        let rendered = render(
+            "{{solution}} in {{pkg_name}} for {{day}}/{{year}} reads {{input}} & {{test_input}} with {{test_params}}, format!(\"{{}}\")",
+            &vars(),
+        )
+        .unwrap();
+        assert_eq!(
+            "Day12 in day12 for 12/2023 reads day12.txt & day12_test.txt with day12_test.toml, format!(\"{{}}\")",
+            rendered
+        );
+
+        let err = render("{{days}}", &vars()).unwrap_err();
+        assert_eq!("unknown template placeholder {{days}}", err.to_string());
+    }
+
+    #[test]
+    fn repo_templates_render() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        for name in [None, Some("grid"), Some("lines"), Some("params")] {
+            let template = load(root, name).unwrap();
+            let rendered = render(&template, &vars()).unwrap();
+            assert!(!rendered.contains("{{"), "{name:?}: {rendered}");
+            assert!(rendered.contains("pub struct Day12"), "{name:?}");
+            assert!(rendered.contains("y2023::day12::solve}"), "{name:?}");
+            assert_eq!(
+                name == Some("params"),
+                uses_test_params(&template),
+                "{name:?}"
+            );
+        }
+        let params = render(&load(root, Some("params")).unwrap(), &vars()).unwrap();
+        assert!(params.contains("impl FromContext for Day12 {"));
+        assert!(params.contains("include_str!(\"../../input/2023/day12_test.toml\")"));
+        assert!(load(root, Some("missing")).is_err());
+    }
+
+    #[test]
+    fn falls_back_to_the_builtin_template() {
+        let rendered = render(&load(Path::new("/"), None).unwrap(), &vars()).unwrap();
+        assert!(rendered.starts_with("// https://adventofcode.com/2023/day/12\n"));
+    }
+}
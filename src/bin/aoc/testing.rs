@@ -0,0 +1,84 @@
+//! Fixtures shared by the `aoc` tests
+
+use std::{
+    cell::Cell,
+    ops::Deref,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+use chrono::{DateTime, TimeZone, Utc};
+
+use crate::unlock::Clock;
+
+/// A fresh directory under the system temp dir, deleted on drop so a failing test doesn't leave
+/// it behind
+pub struct TempDir {
+    path: PathBuf,
+}
+
+impl TempDir {
+    pub fn new() -> TempDir {
+        // tests run in parallel, so each one gets its own directory
+        static COUNT: AtomicUsize = AtomicUsize::new(0);
+        let path = std::env::temp_dir().join(format!(
+            "aoc-test-{}-{}",
+            std::process::id(),
+            COUNT.fetch_add(1, Ordering::Relaxed)
+        ));
+        let _ = std::fs::remove_dir_all(&path);
+        std::fs::create_dir_all(&path).unwrap();
+        TempDir { path }
+    }
+
+    /// writes `contents` to `path` within the directory, creating any missing parents
+    pub fn write(&self, path: impl AsRef<Path>, contents: impl AsRef<[u8]>) {
+        let path = self.path.join(path);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, contents).unwrap();
+    }
+}
+
+impl Deref for TempDir {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.path);
+    }
+}
+
+/// A clock that only moves when slept on
+pub struct TestClock {
+    now: Cell<DateTime<Utc>>,
+}
+
+impl TestClock {
+    pub fn new(now: DateTime<Utc>) -> TestClock {
+        TestClock {
+            now: Cell::new(now),
+        }
+    }
+
+    /// a clock at the given UTC time
+    pub fn at(y: i32, mo: u32, d: u32, h: u32, mi: u32, s: u32) -> TestClock {
+        TestClock::new(Utc.with_ymd_and_hms(y, mo, d, h, mi, s).unwrap())
+    }
+}
+
+impl Clock for TestClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.now.get()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        self.now
+            .set(self.now.get() + chrono::Duration::from_std(duration).unwrap());
+    }
+}
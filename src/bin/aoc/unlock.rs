@@ -0,0 +1,185 @@
+use std::{io::Write, time::Duration};
+
+use anyhow::{bail, Context};
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, TimeZone, Utc};
+
+/// puzzles unlock at midnight US Eastern, which is UTC-5 throughout December
+const UNLOCK_OFFSET_SECS: i32 = -5 * 60 * 60;
+
+/// The time source for everything that depends on unlock times, so tests can pick the time
+pub trait Clock {
+    fn now(&self) -> DateTime<Utc>;
+    fn sleep(&self, duration: Duration);
+}
+
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        std::thread::sleep(duration);
+    }
+}
+
+fn unlock_offset() -> FixedOffset {
+    FixedOffset::east_opt(UNLOCK_OFFSET_SECS).expect("offset is within a day")
+}
+
+/// today's day of the month in the puzzles' timezone
+pub fn default_day(clock: &dyn Clock) -> u32 {
+    clock.now().with_timezone(&unlock_offset()).day()
+}
+
+/// when `day` of `year` becomes available
+pub fn unlock_time(year: usize, day: u32) -> anyhow::Result<DateTime<Utc>> {
+    let date = i32::try_from(year)
+        .ok()
+        .filter(|_| (1..=25).contains(&day))
+        .and_then(|year| NaiveDate::from_ymd_opt(year, 12, day))
+        .with_context(|| format!("{year} day {day} isn't a puzzle"))?;
+    let midnight = date.and_hms_opt(0, 0, 0).expect("midnight exists");
+    Ok(unlock_offset()
+        .from_local_datetime(&midnight)
+        .single()
+        .expect("fixed offsets are never ambiguous")
+        .with_timezone(&Utc))
+}
+
+/// how long until `day` of `year` unlocks, `None` once it has
+pub fn time_until_unlock(
+    clock: &dyn Clock,
+    year: usize,
+    day: u32,
+) -> anyhow::Result<Option<Duration>> {
+    let remaining = unlock_time(year, day)? - clock.now();
+    Ok(remaining
+        .to_std()
+        .ok()
+        .filter(|remaining| !remaining.is_zero()))
+}
+
+/// e.g. `2d 03:04:05`
+pub fn format_duration(remaining: Duration) -> String {
+    let secs = remaining.as_secs() + u64::from(remaining.subsec_nanos() != 0);
+    let (days, secs) = (secs / 86400, secs % 86400);
+    let time = format!("{:02}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60);
+    if days == 0 {
+        time
+    } else {
+        format!("{days}d {time}")
+    }
+}
+
+/// errors if `day` of `year` is still locked
+pub fn ensure_unlocked(clock: &dyn Clock, year: usize, day: u32) -> anyhow::Result<()> {
+    if let Some(remaining) = time_until_unlock(clock, year, day)? {
+        bail!(
+            "{year} day {day} unlocks in {}, pass --wait to wait for it",
+            format_duration(remaining)
+        );
+    }
+
+    Ok(())
+}
+
+/// counts down on `out` until `day` of `year` unlocks
+pub fn wait_for_unlock(
+    clock: &dyn Clock,
+    year: usize,
+    day: u32,
+    out: &mut dyn Write,
+) -> anyhow::Result<()> {
+    let mut waited = false;
+    while let Some(remaining) = time_until_unlock(clock, year, day)? {
+        write!(
+            out,
+            "\r{year} day {day} unlocks in {}",
+            format_duration(remaining)
+        )
+        .and_then(|_| out.flush())
+        .context("failed to show countdown")?;
+        waited = true;
+        // wake on the second so the countdown ticks evenly & the fetch isn't late
+        let tick = Duration::from_nanos(remaining.as_nanos() as u64 % 1_000_000_000);
+        clock.sleep(if tick.is_zero() {
+            Duration::from_secs(1)
+        } else {
+            tick
+        });
+    }
+    if waited {
+        writeln!(out, "\r{year} day {day} is unlocked").context("failed to show countdown")?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use chrono::{TimeZone, Utc};
+
+    use crate::{
+        testing::TestClock,
+        unlock::{
+            default_day, ensure_unlocked, time_until_unlock, unlock_time, wait_for_unlock, Clock,
+        },
+    };
+
+    #[test]
+    fn days_start_at_midnight_eastern() {
+        assert_eq!(
+            Utc.with_ymd_and_hms(2023, 12, 5, 5, 0, 0).unwrap(),
+            unlock_time(2023, 5).unwrap()
+        );
+        assert!(unlock_time(2023, 26).is_err());
+
+        // 03:00 UTC on the 5th is still the 4th in the puzzles' timezone
+        assert_eq!(4, default_day(&TestClock::at(2023, 12, 5, 3, 0, 0)));
+        assert_eq!(5, default_day(&TestClock::at(2023, 12, 5, 5, 0, 0)));
+        assert_eq!(31, default_day(&TestClock::at(2024, 1, 1, 4, 59, 59)));
+    }
+
+    #[test]
+    fn refuses_locked_days() {
+        let clock = TestClock::at(2023, 12, 5, 2, 57, 55);
+        let err = ensure_unlocked(&clock, 2023, 5).unwrap_err();
+        assert_eq!(
+            "2023 day 5 unlocks in 02:02:05, pass --wait to wait for it",
+            err.to_string()
+        );
+        let err = ensure_unlocked(&clock, 2023, 7).unwrap_err();
+        assert!(err.to_string().contains("unlocks in 2d 02:02:05"), "{err}");
+        assert!(ensure_unlocked(&clock, 2023, 4).is_ok());
+        assert!(ensure_unlocked(&clock, 2022, 25).is_ok());
+
+        let clock = TestClock::at(2023, 12, 5, 5, 0, 0);
+        assert_eq!(None, time_until_unlock(&clock, 2023, 5).unwrap());
+    }
+
+    #[test]
+    fn counts_down_to_the_unlock() {
+        let clock = TestClock::at(2023, 12, 5, 4, 59, 57);
+        clock.sleep(Duration::from_millis(500));
+        let mut out = vec![];
+        wait_for_unlock(&clock, 2023, 5, &mut out).unwrap();
+
+        assert_eq!(unlock_time(2023, 5).unwrap(), clock.now());
+        assert_eq!(
+            "\r2023 day 5 unlocks in 00:00:03\
+            \r2023 day 5 unlocks in 00:00:02\
+            \r2023 day 5 unlocks in 00:00:01\
+            \r2023 day 5 is unlocked\n",
+            String::from_utf8(out).unwrap()
+        );
+
+        // nothing is shown for days that are already out
+        let mut out = vec![];
+        wait_for_unlock(&clock, 2023, 5, &mut out).unwrap();
+        assert!(out.is_empty());
+    }
+}
@@ -0,0 +1,89 @@
+use std::{
+    io::ErrorKind,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context};
+
+/// the package `aoc` was built from, which is the one it generates days into
+pub const PACKAGE_NAME: &str = env!("CARGO_PKG_NAME");
+
+/// `root` if given, otherwise the closest ancestor of `cwd` holding this package's `Cargo.toml`
+pub fn find_root(root: Option<&Path>, cwd: &Path) -> anyhow::Result<PathBuf> {
+    if let Some(root) = root {
+        if !is_package_root(root)? {
+            bail!("{root:?} has no Cargo.toml for {PACKAGE_NAME}");
+        }
+        return Ok(root.to_path_buf());
+    }
+
+    for dir in cwd.ancestors() {
+        if is_package_root(dir)? {
+            return Ok(dir.to_path_buf());
+        }
+    }
+
+    bail!("not in {PACKAGE_NAME}: no Cargo.toml for it in {cwd:?} or any parent")
+}
+
+fn is_package_root(dir: &Path) -> anyhow::Result<bool> {
+    let manifest_path = dir.join("Cargo.toml");
+    let manifest = match std::fs::read_to_string(&manifest_path) {
+        Ok(manifest) => manifest,
+        Err(err) if err.kind() == ErrorKind::NotFound => return Ok(false),
+        Err(err) => return Err(err).with_context(|| format!("failed to read {manifest_path:?}")),
+    };
+    // a Cargo.toml that doesn't parse can't be ours, so keep looking rather than failing
+    let Ok(manifest) = toml::from_str::<toml::Table>(&manifest) else {
+        return Ok(false);
+    };
+
+    Ok(manifest
+        .get("package")
+        .and_then(|package| package.get("name"))
+        .and_then(|name| name.as_str())
+        == Some(PACKAGE_NAME))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use crate::{
+        testing::TempDir,
+        workspace::{find_root, PACKAGE_NAME},
+    };
+
+    /// a fresh directory holding `{name}/Cargo.toml` for `package` and an empty `{name}/src/bin`
+    fn workspace(name: &str, package: &str) -> (TempDir, PathBuf) {
+        let dir = TempDir::new();
+        dir.write(
+            format!("{name}/Cargo.toml"),
+            format!("[package]\nname = \"{package}\"\nversion = \"0.1.0\"\n"),
+        );
+        let root = dir.join(name);
+        std::fs::create_dir_all(root.join("src/bin")).unwrap();
+        (dir, root)
+    }
+
+    #[test]
+    fn walks_up_to_the_package_root() {
+        let (_dir, root) = workspace("renamed-clone", PACKAGE_NAME);
+        let found = find_root(None, &root.join("src/bin")).unwrap();
+        assert_eq!(root, found);
+    }
+
+    #[test]
+    fn skips_other_packages() {
+        let (_dir, root) = workspace("other", "something-else");
+        let err = find_root(None, &root.join("src")).unwrap_err();
+        assert!(err.to_string().starts_with("not in "), "{err}");
+        assert!(find_root(Some(&root), Path::new("/")).is_err());
+    }
+
+    #[test]
+    fn root_override_skips_the_search() {
+        let (_dir, root) = workspace("scripted", PACKAGE_NAME);
+        assert_eq!(root, find_root(Some(&root), Path::new("/")).unwrap());
+    }
+}
@@ -0,0 +1,94 @@
+use anyhow::{anyhow, Context};
+use nom::{
+    bytes::complete::tag, character::complete::u32, combinator::all_consuming,
+    multi::separated_list1, sequence::delimited, IResult,
+};
+
+const YEARS_PREFIX: &str = "pub const YEARS: &[&[Solver]] = &[";
+
+fn parse_years_line(input: &str) -> IResult<&str, Vec<u32>> {
+    all_consuming(delimited(
+        tag(YEARS_PREFIX),
+        separated_list1(tag(", "), delimited(tag("y"), u32, tag("::SOLVERS"))),
+        tag("];"),
+    ))(input)
+}
+
+/// `lib` with `pub mod yYYYY;` among its modules & the year in `YEARS`, in order
+pub fn add_year(lib: &str, year: usize) -> anyhow::Result<String> {
+    let module = format!("pub mod y{year};");
+    let mut lines = lib.lines().map(str::to_string).collect::<Vec<_>>();
+
+    let years_line = lines
+        .iter()
+        .position(|line| line.starts_with(YEARS_PREFIX))
+        .context("lib.rs has no YEARS registry")?;
+    let (_, mut years) = parse_years_line(&lines[years_line])
+        .map_err(|err| anyhow!("failed to parse YEARS line: {err}"))?;
+    let year = u32::try_from(year).context("year out of range")?;
+    if !years.contains(&year) {
+        years.push(year);
+        years.sort_unstable();
+    }
+    let entries = years
+        .iter()
+        .map(|year| format!("y{year}::SOLVERS"))
+        .collect::<Vec<_>>();
+    lines[years_line] = format!("{YEARS_PREFIX}{}];", entries.join(", "));
+
+    if !lines.contains(&module) {
+        let mods = lines
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| line.starts_with("pub mod "))
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+        let last = mods.last().context("lib.rs has no modules")?;
+        let insert_at = mods
+            .iter()
+            .find(|&&i| lines[i] > module)
+            .map_or(last + 1, |&i| i);
+        lines.insert(insert_at, module);
+    }
+
+    let mut updated = lines.join("\n");
+    if lib.ends_with('\n') {
+        updated.push('\n');
+    }
+    Ok(updated)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::years::add_year;
+
+    const LIB: &str = "\
+pub mod answers;
+pub mod timing;
+pub mod y2023;
+
+/// every year's solvers
+pub const YEARS: &[&[Solver]] = &[y2023::SOLVERS];
+";
+
+    #[test]
+    fn adds_years_in_order() {
+        let updated = add_year(LIB, 2022).unwrap();
+        assert_eq!(
+            "\
+pub mod answers;
+pub mod timing;
+pub mod y2022;
+pub mod y2023;
+
+/// every year's solvers
+pub const YEARS: &[&[Solver]] = &[y2022::SOLVERS, y2023::SOLVERS];
+",
+            updated
+        );
+        let updated = add_year(&updated, 2024).unwrap();
+        assert!(updated.contains("pub mod y2023;\npub mod y2024;\n"));
+        assert!(updated.contains("&[y2022::SOLVERS, y2023::SOLVERS, y2024::SOLVERS];"));
+        assert_eq!(LIB, add_year(LIB, 2023).unwrap());
+    }
+}
@@ -1 +0,0 @@
-advent_of_code_2023::aoc!(day07);
\ No newline at end of file
@@ -1 +0,0 @@
-advent_of_code_2023::aoc!(day11, is_test);
@@ -0,0 +1,171 @@
+use std::path::PathBuf;
+
+use anyhow::{bail, Context};
+use clap::Parser;
+
+use advent_of_code_2023::{
+    answers::{AnswerBook, ANSWERS_FILE},
+    examples::load_example,
+    input_path, latest_year, read_context, read_input, solvers,
+    timing::TimingReport,
+    Solver,
+};
+
+/// the first advent of code, anything from here on in the leading position is a year
+const FIRST_YEAR: u32 = 2015;
+
+fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+    let is_test = std::env::var_os("TEST").is_some() || args.test;
+    let input_name = match (&args.input, args.example) {
+        (Some(path), _) => path.display().to_string(),
+        (None, Some(number)) => format!("example_{number}"),
+        (None, None) if is_test => "test".to_string(),
+        (None, None) => "real".to_string(),
+    };
+
+    let (year, days) = match args.days.split_first() {
+        Some((&year, days)) if year >= FIRST_YEAR => (Some(year), days),
+        _ => (None, args.days.as_slice()),
+    };
+    let solvers = if args.all {
+        if !days.is_empty() {
+            bail!("--all can only be combined with a year");
+        }
+        solvers()
+            .filter(|solver| year.is_none_or(|year| solver.year == year))
+            .collect::<Vec<_>>()
+    } else {
+        let year = match year {
+            Some(year) => year,
+            None => latest_year().context("no days are registered")?,
+        };
+        days.iter()
+            .map(|&day| {
+                Solver::find(year, day)
+                    .with_context(|| format!("{year} day {day} is not registered"))
+            })
+            .collect::<Result<Vec<_>, _>>()?
+    };
+    if solvers.is_empty() {
+        bail!("no days to run");
+    }
+    if args.input.is_some() && solvers.len() != 1 {
+        bail!("--input can only be used with a single day");
+    }
+
+    let mut answers = AnswerBook::load(ANSWERS_FILE)?;
+    let mut failures = 0;
+    let mut errors = 0;
+    let mut report = TimingReport::default();
+
+    for solver in solvers {
+        let (year, day, label) = (solver.year, solver.name, solver.label());
+        let (input, ctx, expected) = match args.example {
+            Some(number) => {
+                let example = load_example(year, day, number)?;
+                (example.input, example.ctx, Some(example.expected))
+            }
+            None => {
+                let path = match &args.input {
+                    Some(path) => path.clone(),
+                    None => input_path(year, day, is_test),
+                };
+                (read_input(&path)?, read_context(&path)?, None)
+            }
+        };
+        let ctx = ctx.with_param_overrides(&args.params)?;
+        let solution = if args.time {
+            let timed = (solver.time)(&input, &ctx)?;
+            report.push(&label, timed.timings);
+            timed.result
+        } else {
+            (solver.solve)(&input, &ctx)?
+        };
+
+        if args.check {
+            let checks = match &expected {
+                Some(expected) => expected.check(&solution),
+                None => answers.check(year, day, &input_name, &solution),
+            };
+            for (part, check) in checks.iter().enumerate() {
+                println!("{label} {input_name} part {part}: {check}", part = part + 1);
+                failures += check.is_fail() as usize;
+            }
+        } else {
+            println!("{label}: {solution}");
+            errors += solution.errors().count();
+        }
+
+        if args.record {
+            answers.record(year, day, &input_name, &solution)?;
+        }
+    }
+
+    if args.record {
+        answers.save(ANSWERS_FILE)?;
+    }
+
+    if args.time {
+        println!("\n{report}");
+    }
+    if let Some(path) = &args.markdown {
+        std::fs::write(path, report.to_markdown())
+            .with_context(|| format!("failed to write timings to {path:?}"))?;
+    }
+    if let Some(path) = &args.json {
+        std::fs::write(path, report.to_json()?)
+            .with_context(|| format!("failed to write timings to {path:?}"))?;
+    }
+
+    if failures != 0 {
+        bail!("{failures} answers did not match {ANSWERS_FILE}");
+    }
+    if errors != 0 {
+        bail!("{errors} parts failed");
+    }
+
+    Ok(())
+}
+
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+struct Args {
+    /// `[YEAR] DAY...`, days without a year are from the latest one
+    #[arg(value_name = "DAYS", required_unless_present = "all")]
+    days: Vec<u32>,
+    /// run every day, or every day of the year if one is given
+    #[arg(short, long, default_value_t = false)]
+    all: bool,
+    #[arg(short, long, default_value_t = false)]
+    test: bool,
+    /// use `input/YYYY/dayNN/example_N.txt`, checked against the answers in its sidecar
+    #[arg(short, long, conflicts_with_all = ["test", "record"])]
+    example: Option<u32>,
+    /// solve the input at PATH instead, `-` reads from stdin
+    #[arg(
+        short,
+        long,
+        value_name = "PATH",
+        conflicts_with_all = ["all", "test", "example", "check", "record"]
+    )]
+    input: Option<PathBuf>,
+    /// override a puzzle parameter, e.g. `-p multiplier=100`
+    #[arg(short, long = "param")]
+    params: Vec<String>,
+    /// compare answers against answers.toml
+    #[arg(short, long, default_value_t = false, conflicts_with = "record")]
+    check: bool,
+    /// time parse, part 1 & part 2 of each day
+    #[arg(long, default_value_t = false)]
+    time: bool,
+    /// export the timing table as markdown to PATH
+    #[arg(long, value_name = "PATH", requires = "time")]
+    markdown: Option<PathBuf>,
+    /// export the timings as json to PATH
+    #[arg(long, value_name = "PATH", requires = "time")]
+    json: Option<PathBuf>,
+    /// write answers to answers.toml
+    #[arg(short, long, default_value_t = false)]
+    record: bool,
+}
@@ -0,0 +1 @@
+advent_of_code_2023::aoc!(y2023, day04);
\ No newline at end of file
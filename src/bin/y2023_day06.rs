@@ -0,0 +1 @@
+advent_of_code_2023::aoc!(y2023, day06);
\ No newline at end of file
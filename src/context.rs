@@ -0,0 +1,156 @@
+use std::{collections::BTreeMap, io::ErrorKind, path::Path, str::FromStr};
+
+use anyhow::{anyhow, Context};
+use serde::{Deserialize, Serialize};
+
+use crate::answers::RecordedAnswers;
+
+/// Named puzzle parameters for a single input, e.g. the expansion `multiplier` for day 11
+///
+/// Loaded from the `[params]` table of a sidecar file next to the input, so
+/// `input/2023/day11_test.txt` reads its parameters from `input/2023/day11_test.toml`.
+/// Solvers fall back to the real puzzle's values for anything not set.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct SolveContext {
+    params: BTreeMap<String, toml::Value>,
+}
+
+/// The toml file sitting next to an input, e.g. `input/2023/day10/example_2.toml`
+///
+/// Examples also carry their expected `[answers]`, which the example tests check.
+#[derive(Debug, Default, Deserialize)]
+pub struct Sidecar {
+    #[serde(default)]
+    pub params: SolveContext,
+    #[serde(default)]
+    pub answers: RecordedAnswers,
+}
+
+impl Sidecar {
+    pub fn parse(contents: &str) -> anyhow::Result<Sidecar> {
+        toml::from_str(contents).context("failed to parse sidecar")
+    }
+
+    /// reads the sidecar for `input_path`, an absent sidecar is treated as empty
+    pub fn load(input_path: impl AsRef<Path>) -> anyhow::Result<Sidecar> {
+        let sidecar_path = input_path.as_ref().with_extension("toml");
+        match std::fs::read_to_string(&sidecar_path) {
+            Ok(contents) => Sidecar::parse(&contents)
+                .with_context(|| format!("failed to load sidecar {sidecar_path:?}")),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(Sidecar::default()),
+            Err(err) => {
+                Err(err).with_context(|| format!("failed to read sidecar {sidecar_path:?}"))
+            }
+        }
+    }
+}
+
+impl SolveContext {
+    pub fn from_sidecar(contents: &str) -> anyhow::Result<SolveContext> {
+        Ok(Sidecar::parse(contents)?.params)
+    }
+
+    /// the params as a sidecar's `[params]` table, to be read back with [`SolveContext::load`]
+    pub fn to_sidecar(&self) -> anyhow::Result<String> {
+        #[derive(Serialize)]
+        struct Params<'a> {
+            params: &'a SolveContext,
+        }
+
+        toml::to_string(&Params { params: self }).context("failed to serialise params")
+    }
+
+    /// reads the params from the sidecar for `input_path`, if there is one
+    pub fn load(input_path: impl AsRef<Path>) -> anyhow::Result<SolveContext> {
+        Ok(Sidecar::load(input_path)?.params)
+    }
+
+    pub fn with_param(mut self, name: &str, value: impl Into<toml::Value>) -> SolveContext {
+        self.params.insert(name.to_string(), value.into());
+        self
+    }
+
+    /// parses a `name=value` override as given on the command line
+    pub fn with_param_override(self, param: &str) -> anyhow::Result<SolveContext> {
+        let (name, value) = param
+            .split_once('=')
+            .with_context(|| format!("expected name=value, got {param:?}"))?;
+        Ok(self.with_param(name, value))
+    }
+
+    pub fn with_param_overrides<S: AsRef<str>>(self, params: &[S]) -> anyhow::Result<SolveContext> {
+        params
+            .iter()
+            .try_fold(self, |ctx, param| ctx.with_param_override(param.as_ref()))
+    }
+
+    pub fn param<T>(&self, name: &str) -> anyhow::Result<Option<T>>
+    where
+        T: FromStr,
+        T::Err: std::fmt::Display,
+    {
+        let Some(value) = self.params.get(name) else {
+            return Ok(None);
+        };
+        let raw = match value {
+            toml::Value::String(s) => s.clone(),
+            value => value.to_string(),
+        };
+        raw.parse()
+            .map(Some)
+            .map_err(|err| anyhow!("invalid value {raw:?} for parameter {name}: {err}"))
+    }
+
+    pub fn param_or<T>(&self, name: &str, default: T) -> anyhow::Result<T>
+    where
+        T: FromStr,
+        T::Err: std::fmt::Display,
+    {
+        Ok(self.param(name)?.unwrap_or(default))
+    }
+}
+
+/// Builds a day's solver from the parameters of the input it is about to solve
+pub trait FromContext: Sized {
+    fn from_context(ctx: &SolveContext) -> anyhow::Result<Self>;
+}
+
+impl<T> FromContext for T
+where
+    T: Default,
+{
+    fn from_context(_ctx: &SolveContext) -> anyhow::Result<Self> {
+        Ok(T::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::context::SolveContext;
+
+    #[test]
+    fn reads_params_from_sidecar() {
+        let ctx = SolveContext::from_sidecar("[params]\nmultiplier = 10\nskip = true").unwrap();
+        assert_eq!(Some(10), ctx.param::<usize>("multiplier").unwrap());
+        assert_eq!(Some(true), ctx.param::<bool>("skip").unwrap());
+        assert_eq!(64, ctx.param_or("steps", 64).unwrap());
+
+        let sidecar = ctx.to_sidecar().unwrap();
+        let ctx = SolveContext::from_sidecar(&sidecar).unwrap();
+        assert_eq!(Some(10), ctx.param::<usize>("multiplier").unwrap());
+        assert_eq!(Some(true), ctx.param::<bool>("skip").unwrap());
+    }
+
+    #[test]
+    fn overrides_params() {
+        let ctx = SolveContext::default()
+            .with_param_override("multiplier=100")
+            .unwrap();
+        assert_eq!(Some(100), ctx.param::<usize>("multiplier").unwrap());
+        assert!(SolveContext::default()
+            .with_param("steps", "many")
+            .param::<usize>("steps")
+            .is_err());
+    }
+}
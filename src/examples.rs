@@ -0,0 +1,105 @@
+use std::{
+    io::ErrorKind,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Context;
+
+use crate::{
+    answers::RecordedAnswers,
+    context::{Sidecar, SolveContext},
+    input_dir,
+};
+
+/// A puzzle example stored as `input/YYYY/dayNN/example_N.txt` (under `AOC_INPUT_DIR` if set)
+///
+/// Its sidecar `example_N.toml` holds the expected `[answers]` for whichever
+/// parts the example illustrates, plus any `[params]` it needs.
+#[derive(Debug)]
+pub struct Example {
+    pub number: u32,
+    pub path: PathBuf,
+    pub input: String,
+    pub ctx: SolveContext,
+    pub expected: RecordedAnswers,
+}
+
+pub fn example_dir(year: u32, day: &str) -> PathBuf {
+    input_dir().join(year.to_string()).join(day)
+}
+
+pub fn example_path(year: u32, day: &str, number: u32) -> PathBuf {
+    example_dir(year, day).join(format!("example_{number}.txt"))
+}
+
+pub fn load_example(year: u32, day: &str, number: u32) -> anyhow::Result<Example> {
+    let path = example_path(year, day, number);
+    let input = std::fs::read_to_string(&path)
+        .with_context(|| format!("failed to read example {path:?}"))?;
+    let Sidecar { params, answers } = Sidecar::load(&path)?;
+
+    Ok(Example {
+        number,
+        path,
+        input,
+        ctx: params,
+        expected: answers,
+    })
+}
+
+/// every example stored for `day` in numeric order, days without examples have none
+pub fn examples(year: u32, day: &str) -> anyhow::Result<Vec<Example>> {
+    let dir = example_dir(year, day);
+    let entries = match std::fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == ErrorKind::NotFound => return Ok(vec![]),
+        Err(err) => return Err(err).with_context(|| format!("failed to read {dir:?}")),
+    };
+
+    let mut numbers = vec![];
+    for entry in entries {
+        let path = entry
+            .with_context(|| format!("failed to read {dir:?}"))?
+            .path();
+        if path.extension().is_some_and(|ext| ext == "txt") {
+            numbers.push(parse_example_number(&path)?);
+        }
+    }
+    numbers.sort_unstable();
+
+    numbers
+        .into_iter()
+        .map(|number| load_example(year, day, number))
+        .collect()
+}
+
+fn parse_example_number(path: &Path) -> anyhow::Result<u32> {
+    path.file_stem()
+        .and_then(|stem| stem.to_str())
+        .and_then(|stem| stem.strip_prefix("example_"))
+        .and_then(|number| number.parse().ok())
+        .with_context(|| format!("expected example_N.txt, found {path:?}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{examples::examples, solvers};
+
+    #[test]
+    fn examples_match_expected_answers() {
+        for solver in solvers() {
+            for example in examples(solver.year, solver.name).unwrap() {
+                let path = &example.path;
+                assert!(
+                    !example.expected.is_empty(),
+                    "{path:?} has no expected answers"
+                );
+                let solution = (solver.solve)(&example.input, &example.ctx)
+                    .unwrap_or_else(|err| panic!("{path:?} failed to solve: {err:?}"));
+                for check in example.expected.check(&solution) {
+                    assert!(!check.is_fail(), "{path:?}: {check}");
+                }
+            }
+        }
+    }
+}
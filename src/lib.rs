@@ -1,12 +1,23 @@
 use std::{
-    cmp::Reverse,
+    borrow::Cow,
     fmt::{Debug, Display, Formatter},
+    io::Read,
+    path::{Path, PathBuf},
 };
 
-use arrayvec::ArrayVec;
+use anyhow::Context;
 use clap::Parser;
+use num::BigInt;
 
-pub mod days;
+use context::{FromContext, SolveContext};
+
+pub mod answers;
+pub mod context;
+pub mod examples;
+pub mod ocr;
+pub mod parse;
+pub mod timing;
+pub mod y2023;
 
 macro_rules! impl_answer_enum {
     ( $( ($variant:tt, $ty:ty) ),* ) => {
@@ -45,23 +56,6 @@ macro_rules! impl_answer_enum {
             }
         }
 
-        impl Eq for Answers {}
-
-        impl PartialEq for Answers {
-            fn eq(&self, other: &Self) -> bool {
-                let val_self = match self {
-                    $(
-                    Answers::$variant(v) => format!("{v}"),
-                    )*
-                };
-                let val_other = match other {
-                    $(
-                    Answers::$variant(v) => format!("{v}"),
-                    )*
-                };
-                val_self == val_other
-            }
-        }
     }
 }
 
@@ -78,15 +72,139 @@ impl_answer_enum! {
     (I64, i64),
     (I32, i32),
     (I16, i16),
-    (I8, i8)
+    (I8, i8),
+    (BigInt, BigInt),
+    (Grid, ocr::Grid)
+}
+
+impl Answers {
+    /// the answer as it's submitted, drawings are read into their letters
+    pub fn text(&self) -> anyhow::Result<String> {
+        match self {
+            Answers::Grid(grid) => Ok(grid
+                .decode()
+                .context("couldn't read the drawn answer")?
+                .to_string()),
+            answer => Ok(answer.to_string()),
+        }
+    }
+}
+
+/// An answer's value whatever type it was given as, integers are only big if they don't fit an `i128`
+#[derive(PartialEq)]
+enum Canonical<'a> {
+    Integer(i128),
+    Big(Cow<'a, BigInt>),
+    Text(Cow<'a, str>),
+    /// a drawing that can't be read, it only equals the same drawing
+    Art(&'a str),
+}
+
+impl Canonical<'_> {
+    fn integer<T>(n: T) -> Canonical<'static>
+    where
+        T: Copy,
+        i128: TryFrom<T>,
+        BigInt: From<T>,
+    {
+        i128::try_from(n).map_or_else(
+            |_| Canonical::Big(Cow::Owned(BigInt::from(n))),
+            Canonical::Integer,
+        )
+    }
 }
 
+impl Answers {
+    fn canonical(&self) -> Canonical<'_> {
+        match self {
+            Answers::String(s) => Canonical::Text(Cow::Borrowed(s)),
+            Answers::Usize(n) => Canonical::integer(*n),
+            Answers::U128(n) => Canonical::integer(*n),
+            Answers::U64(n) => Canonical::integer(*n),
+            Answers::U32(n) => Canonical::integer(*n),
+            Answers::U16(n) => Canonical::integer(*n),
+            Answers::U8(n) => Canonical::integer(*n),
+            Answers::Isize(n) => Canonical::integer(*n),
+            Answers::I128(n) => Canonical::integer(*n),
+            Answers::I64(n) => Canonical::integer(*n),
+            Answers::I32(n) => Canonical::integer(*n),
+            Answers::I16(n) => Canonical::integer(*n),
+            Answers::I8(n) => Canonical::integer(*n),
+            Answers::BigInt(n) => {
+                i128::try_from(n).map_or(Canonical::Big(Cow::Borrowed(n)), Canonical::Integer)
+            }
+            Answers::Grid(grid) => match grid.text() {
+                Some(text) => Canonical::Text(Cow::Borrowed(text)),
+                None => Canonical::Art(grid.art()),
+            },
+        }
+    }
+}
+
+/// compares values rather than types, so `5usize`, `5isize` & `BigInt::from(5)` are all equal
+impl PartialEq for Answers {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.canonical(), other.canonical()) {
+            // only a number & text need formatting, the text has to be how the number prints
+            (Canonical::Integer(n), Canonical::Text(text))
+            | (Canonical::Text(text), Canonical::Integer(n)) => n.to_string() == text,
+            (Canonical::Big(n), Canonical::Text(text))
+            | (Canonical::Text(text), Canonical::Big(n)) => n.to_string() == text,
+            (a, b) => a == b,
+        }
+    }
+}
+
+impl Eq for Answers {}
+
 impl From<&'_ str> for Answers {
     fn from(s: &'_ str) -> Self {
         Answers::String(s.to_string())
     }
 }
 
+/// A single part's answer, `()` for a part that isn't implemented yet & `None` for one that's
+/// skipped for this input
+pub trait IntoPart {
+    fn into_part(self) -> anyhow::Result<Option<Answers>>;
+}
+
+impl IntoPart for () {
+    fn into_part(self) -> anyhow::Result<Option<Answers>> {
+        Ok(None)
+    }
+}
+
+impl<A> IntoPart for A
+where
+    A: Into<Answers>,
+{
+    fn into_part(self) -> anyhow::Result<Option<Answers>> {
+        Ok(Some(self.into()))
+    }
+}
+
+impl<A> IntoPart for anyhow::Result<A>
+where
+    A: IntoPart,
+{
+    fn into_part(self) -> anyhow::Result<Option<Answers>> {
+        self?.into_part()
+    }
+}
+
+impl<A> IntoPart for Option<A>
+where
+    A: IntoPart,
+{
+    fn into_part(self) -> anyhow::Result<Option<Answers>> {
+        match self {
+            Some(answer) => answer.into_part(),
+            None => Ok(None),
+        }
+    }
+}
+
 pub trait IntoDayResult: Sized {
     fn into_result(self) -> anyhow::Result<DayResult> {
         Ok(self.into_day_result())
@@ -97,8 +215,8 @@ pub trait IntoDayResult: Sized {
 impl IntoDayResult for () {
     fn into_day_result(self) -> DayResult {
         DayResult {
-            part1: None,
-            part2: None,
+            part1: Ok(None),
+            part2: Ok(None),
         }
     }
 }
@@ -109,225 +227,252 @@ where
 {
     fn into_day_result(self) -> DayResult {
         DayResult {
-            part1: Some(self.into()),
-            part2: None,
+            part1: Ok(Some(self.into())),
+            part2: Ok(None),
         }
     }
 }
 
 impl<A> IntoDayResult for (A,)
 where
-    A: Into<Answers>,
+    A: IntoPart,
 {
     fn into_day_result(self) -> DayResult {
         let (a,) = self;
         DayResult {
-            part1: Some(a.into()),
-            part2: None,
+            part1: a.into_part(),
+            part2: Ok(None),
         }
     }
 }
 
+/// covers `(A, ())` for days with only part 1 & `(A, anyhow::Result<B>)` for a part that failed
 impl<A, B> IntoDayResult for (A, B)
 where
-    A: Into<Answers>,
-    B: Into<Answers>,
+    A: IntoPart,
+    B: IntoPart,
 {
     fn into_day_result(self) -> DayResult {
         let (a, b) = self;
         DayResult {
-            part1: Some(a.into()),
-            part2: Some(b.into()),
+            part1: a.into_part(),
+            part2: b.into_part(),
         }
     }
 }
 
-#[derive(Debug, PartialEq, Eq)]
+/// Each part's answer, `Ok(None)` while it isn't implemented, so a failing
+/// part 2 doesn't lose part 1
+#[derive(Debug)]
 pub struct DayResult {
-    pub part1: Option<Answers>,
-    pub part2: Option<Answers>,
+    pub part1: anyhow::Result<Option<Answers>>,
+    pub part2: anyhow::Result<Option<Answers>>,
+}
+
+impl DayResult {
+    /// `part`'s answer, if it has one, there's none for parts other than 1 & 2
+    pub fn answer(&self, part: u8) -> Option<&Answers> {
+        match part {
+            1 => self.part1.as_ref().ok()?.as_ref(),
+            2 => self.part2.as_ref().ok()?.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// the errors of the parts that failed
+    pub fn errors(&self) -> impl Iterator<Item = &anyhow::Error> {
+        [&self.part1, &self.part2]
+            .into_iter()
+            .filter_map(|part| part.as_ref().err())
+    }
+}
+
+/// errors are equal if they read the same, tests can then expect a part to fail
+impl PartialEq for DayResult {
+    fn eq(&self, other: &Self) -> bool {
+        fn part_eq(
+            a: &anyhow::Result<Option<Answers>>,
+            b: &anyhow::Result<Option<Answers>>,
+        ) -> bool {
+            match (a, b) {
+                (Ok(a), Ok(b)) => a == b,
+                (Err(a), Err(b)) => format!("{a:#}") == format!("{b:#}"),
+                _ => false,
+            }
+        }
+        part_eq(&self.part1, &other.part1) && part_eq(&self.part2, &other.part2)
+    }
+}
+
+impl Eq for DayResult {}
+
+fn fmt_part(part: &anyhow::Result<Option<Answers>>) -> String {
+    match part {
+        Ok(Some(answer)) => answer.to_string(),
+        Ok(None) => "TBC".to_string(),
+        Err(err) => format!("error: {err:#}"),
+    }
 }
 
 impl Display for DayResult {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         writeln!(f, "DayResult {{")?;
-        writeln!(
-            f,
-            "\tpart 1: {p1}",
-            p1 = self
-                .part1
-                .as_ref()
-                .map(|v| v.to_string())
-                .unwrap_or("TBC".to_string())
-        )?;
-        writeln!(
-            f,
-            "\tpart 2: {p2}",
-            p2 = self
-                .part2
-                .as_ref()
-                .map(|v| v.to_string())
-                .unwrap_or("TBC".to_string())
-        )?;
+        writeln!(f, "\tpart 1: {}", fmt_part(&self.part1))?;
+        writeln!(f, "\tpart 2: {}", fmt_part(&self.part2))?;
         writeln!(f, "}}")?;
         Ok(())
     }
 }
 
-trait TryConvert {
-    type Into;
-    fn try_convert(self) -> Result<Self::Into, CollectError>;
-}
+pub trait Day {
+    type Parsed<'a>;
+    type Part1;
+    type Part2;
 
-impl<T, const N: usize> TryConvert for ArrayVec<T, N> {
-    type Into = [T; N];
+    fn parse<'a>(&self, input: &'a str) -> anyhow::Result<Self::Parsed<'a>>;
 
-    fn try_convert(self) -> Result<Self::Into, CollectError> {
-        self.into_inner().map_err(|arr| CollectError {
-            expected: N,
-            actual: arr.len(),
-        })
-    }
-}
+    fn part1(&self, parsed: &Self::Parsed<'_>) -> anyhow::Result<Self::Part1>;
 
-trait CollectN<T>
-where
-    Self: Sized,
-{
-    fn try_collect_largest<const N: usize>(self) -> Result<[T; N], CollectError>
-    where
-        T: Ord,
-    {
-        self.collect_smallest().try_convert()
-    }
+    fn part2(&self, parsed: &Self::Parsed<'_>) -> anyhow::Result<Self::Part2>;
 
-    fn try_collect_smallest<const N: usize>(self) -> Result<[T; N], CollectError>
+    /// only a failed parse fails the whole day, each part keeps its own error
+    fn solve(&self, input: &str) -> anyhow::Result<DayResult>
     where
-        T: Ord,
+        Self::Part1: IntoPart,
+        Self::Part2: IntoPart,
     {
-        self.collect_largest().try_convert()
-    }
+        let parsed = self.parse(input)?;
+        let p1 = self.part1(&parsed);
+        let p2 = self.part2(&parsed);
 
-    fn try_collect_by_fn<const N: usize, F>(self, f: F) -> Result<[T; N], CollectError>
-    where
-        F: for<'a> Callable<&'a T>,
-    {
-        self.collect_by_fn(f).try_convert()
+        (p1, p2).into_result()
     }
+}
 
-    fn collect_largest<const N: usize>(self) -> ArrayVec<T, N>
-    where
-        T: Ord,
-    {
-        self.collect_by_fn((|v| Reverse(v)) as for<'a> fn(&'a T) -> Reverse<&'a T>)
-    }
+/// every year's solvers, oldest first, `aoc` adds new years here
+pub const YEARS: &[&[Solver]] = &[y2023::SOLVERS];
 
-    fn collect_smallest<const N: usize>(self) -> ArrayVec<T, N>
-    where
-        T: Ord,
-    {
-        self.collect_by_fn((|v| v) as for<'a> fn(&'a T) -> &'a T)
+pub struct Solver {
+    pub year: u32,
+    pub day: u32,
+    pub name: &'static str,
+    pub parse: fn(&str, &SolveContext) -> anyhow::Result<()>,
+    pub solve: fn(&str, &SolveContext) -> anyhow::Result<DayResult>,
+    pub time: fn(&str, &SolveContext) -> anyhow::Result<timing::TimedResult>,
+}
+
+impl Solver {
+    pub fn find(year: u32, day: u32) -> Option<&'static Solver> {
+        solvers().find(|solver| solver.year == year && solver.day == day)
     }
 
-    fn collect_by_fn<const N: usize, F>(self, f: F) -> ArrayVec<T, N>
-    where
-        F: for<'a> Callable<&'a T>;
+    /// e.g. `2023/day05`, unique across years
+    pub fn label(&self) -> String {
+        format!("{}/{}", self.year, self.name)
+    }
 }
 
-#[derive(Debug)]
-struct CollectError {
-    expected: usize,
-    actual: usize,
+/// every registered solver, in year then day order
+pub fn solvers() -> impl Iterator<Item = &'static Solver> {
+    YEARS.iter().flat_map(|year| year.iter())
 }
 
-impl Display for CollectError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "CollectError: expected {expected} items, only consumed {actual}",
-            expected = self.expected,
-            actual = self.actual
-        )
-    }
+/// the most recent year with any solvers, which is what a day without a year refers to
+pub fn latest_year() -> Option<u32> {
+    solvers().map(|solver| solver.year).max()
 }
 
-impl std::error::Error for CollectError {}
-
-impl<I, T> CollectN<T> for I
+/// parses without solving, for benchmarking the parser alone
+pub fn parse_day<D>(input: &str, ctx: &SolveContext) -> anyhow::Result<()>
 where
-    I: Iterator<Item = T>,
+    D: Day + FromContext,
 {
-    fn collect_by_fn<const N: usize, F>(self, f: F) -> ArrayVec<T, N>
-    where
-        F: for<'a> Callable<&'a T>,
-    {
-        let mut res = ArrayVec::new();
-
-        if N == 0 {
-            return res;
-        }
-
-        let comparer = |a: &_, b: &_| Ord::cmp(&f.call(a), &f.call(b));
+    let parsed = D::from_context(ctx)?.parse(input)?;
+    std::hint::black_box(parsed);
+    Ok(())
+}
 
-        for (i, item) in self.enumerate() {
-            if i >= N {
-                let last = res
-                    .pop()
-                    .expect("there should always be a value as res cap is > 0");
-                let smallest = std::cmp::min_by(item, last, comparer);
+pub fn solve_day<D>(input: &str, ctx: &SolveContext) -> anyhow::Result<DayResult>
+where
+    D: Day + FromContext,
+    D::Part1: IntoPart,
+    D::Part2: IntoPart,
+{
+    D::from_context(ctx)?.solve(input)
+}
 
-                res.push(smallest);
-            } else {
-                res.push(item);
-            }
+// module names are always `dayNN`
+pub const fn day_number(name: &str) -> u32 {
+    let name = name.as_bytes();
+    let mut day = 0;
+    let mut i = 3;
+    while i < name.len() {
+        day = day * 10 + (name[i] - b'0') as u32;
+        i += 1;
+    }
+    day
+}
 
-            res.sort_unstable_by(comparer);
+/// a `Solver` for `$day::$solution` in the year module it's used in, which defines `YEAR`
+#[macro_export]
+macro_rules! solver {
+    ($day:tt, $solution:tt) => {
+        $crate::Solver {
+            year: YEAR,
+            day: $crate::day_number(stringify!($day)),
+            name: stringify!($day),
+            parse: $crate::parse_day::<$day::$solution>,
+            solve: $crate::solve_day::<$day::$solution>,
+            time: $crate::timing::time_day::<$day::$solution>,
         }
-
-        res
-    }
+    };
 }
 
-trait Callable<T> {
-    type Output: Ord;
+/// passed as an input path to read the puzzle from stdin instead
+pub const STDIN_PATH: &str = "-";
 
-    fn call(&self, arg: T) -> Self::Output;
+/// `AOC_INPUT_DIR` if set, otherwise `input` relative to the cwd
+pub fn input_dir() -> PathBuf {
+    std::env::var_os("AOC_INPUT_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("input"))
 }
 
-impl<F, T, U> Callable<T> for F
-where
-    U: Ord,
-    F: Fn(T) -> U,
-{
-    type Output = U;
-
-    fn call(&self, arg: T) -> Self::Output {
-        (*self)(arg)
+pub fn input_path(year: u32, day: &str, is_test: bool) -> PathBuf {
+    if is_test {
+        input_dir().join(format!("{year}/{day}_test.txt"))
+    } else {
+        input_dir().join(format!("{year}/{day}.txt"))
     }
 }
 
-trait Pipe: Sized {
-    fn pipe<F, U>(self, f: F) -> U
-    where
-        F: FnMut(Self) -> U;
+pub fn read_input(path: &Path) -> anyhow::Result<String> {
+    if path == Path::new(STDIN_PATH) {
+        let mut input = String::new();
+        std::io::stdin()
+            .read_to_string(&mut input)
+            .context("failed to read input from stdin")?;
+        Ok(input)
+    } else {
+        std::fs::read_to_string(path).with_context(|| format!("failed to read input {path:?}"))
+    }
 }
 
-impl<T> Pipe for T {
-    fn pipe<F, U>(self, mut f: F) -> U
-    where
-        F: FnMut(Self) -> U,
-    {
-        f(self)
+/// the params for the input at `path`, stdin never has a sidecar
+pub fn read_context(path: &Path) -> anyhow::Result<SolveContext> {
+    if path == Path::new(STDIN_PATH) {
+        Ok(SolveContext::default())
+    } else {
+        SolveContext::load(path)
     }
 }
 
-pub fn get_input(day: &str, is_test: bool) -> std::io::Result<String> {
-    let filepath = if is_test {
-        format!("input/{day}_test.txt")
-    } else {
-        format!("input/{day}.txt")
-    };
-    std::fs::read_to_string(filepath)
+pub fn get_input(year: u32, day: &str, is_test: bool) -> anyhow::Result<String> {
+    read_input(&input_path(year, day, is_test))
+}
+
+pub fn get_context(year: u32, day: &str, is_test: bool) -> anyhow::Result<SolveContext> {
+    read_context(&input_path(year, day, is_test))
 }
 
 #[derive(Parser, Debug)]
@@ -335,39 +480,54 @@ pub fn get_input(day: &str, is_test: bool) -> std::io::Result<String> {
 pub struct Args {
     #[arg(short, long, default_value_t = false)]
     pub test: bool,
+    /// solve the input at PATH instead, `-` reads from stdin
+    #[arg(short, long, value_name = "PATH", conflicts_with = "test")]
+    pub input: Option<PathBuf>,
+    /// override a puzzle parameter, e.g. `-p multiplier=100`
+    #[arg(short, long = "param")]
+    pub params: Vec<String>,
+    /// time parse, part 1 & part 2
+    #[arg(long, default_value_t = false)]
+    pub time: bool,
 }
 
-#[macro_export]
-macro_rules! aoc_args_input_only {
-    ($solver:expr, $a:tt, $b:tt) => {
-        $solver($a)
-    };
-}
-
-#[macro_export]
-macro_rules! aoc_args_both {
-    ($solver:expr, $a:tt, $b:tt) => {
-        $solver($a, $b)
-    };
+impl Args {
+    pub fn input_path(&self, year: u32, day: &str) -> PathBuf {
+        match &self.input {
+            Some(path) => path.clone(),
+            None => input_path(year, day, std::env::var_os("TEST").is_some() || self.test),
+        }
+    }
 }
 
 #[macro_export]
 macro_rules! aoc_impl {
-    ($day:tt, $solver:tt) => {
+    ($year:tt, $day:tt) => {
+        use anyhow::Context;
         use clap::Parser;
 
-        use $crate::days::$day::solve;
-        use $crate::get_input;
         use $crate::Args;
+        use $crate::Solver;
+        use $crate::{read_context, read_input};
 
         fn main() -> anyhow::Result<()> {
             let args = Args::parse();
+            let year = $crate::$year::YEAR;
             let day = stringify!($day);
-            let is_test = std::env::var_os("TEST").is_some() || args.test;
-            let input = get_input(day, is_test)?;
-            let solution = $solver!(solve, (&input), is_test)?;
-
-            println!("{day}: {solution}");
+            let solver = Solver::find(year, $crate::day_number(day))
+                .with_context(|| format!("{year} {day} is not registered"))?;
+            let path = args.input_path(year, day);
+            let input = read_input(&path)?;
+            let ctx = read_context(&path)?.with_param_overrides(&args.params)?;
+            if args.time {
+                let timed = (solver.time)(&input, &ctx)?;
+                let mut report = $crate::timing::TimingReport::default();
+                report.push(&solver.label(), timed.timings);
+                println!("{}: {}\n{report}", solver.label(), timed.result);
+            } else {
+                let solution = (solver.solve)(&input, &ctx)?;
+                println!("{}: {solution}", solver.label());
+            }
 
             Ok(())
         }
@@ -376,12 +536,92 @@ macro_rules! aoc_impl {
 
 #[macro_export]
 macro_rules! aoc {
-    ($day:tt) => {
-        use $crate::aoc_args_input_only;
-        $crate::aoc_impl!($day, aoc_args_input_only);
-    };
-    ($day:tt, is_test) => {
-        use $crate::aoc_args_both;
-        $crate::aoc_impl!($day, aoc_args_both);
+    ($year:tt, $day:tt) => {
+        $crate::aoc_impl!($year, $day);
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use anyhow::{anyhow, bail};
+    use num::BigInt;
+
+    use crate::{Answers, Day, IntoDayResult};
+
+    struct Failing;
+
+    impl Day for Failing {
+        type Parsed<'a> = &'a str;
+        type Part1 = usize;
+        type Part2 = u64;
+
+        fn parse<'a>(&self, input: &'a str) -> anyhow::Result<Self::Parsed<'a>> {
+            Ok(input)
+        }
+
+        fn part1(&self, input: &Self::Parsed<'_>) -> anyhow::Result<Self::Part1> {
+            Ok(input.len())
+        }
+
+        fn part2(&self, _input: &Self::Parsed<'_>) -> anyhow::Result<Self::Part2> {
+            bail!("no cycle found")
+        }
+    }
+
+    #[test]
+    fn failing_parts_keep_the_others() {
+        let solution = Failing.solve("abc").unwrap();
+        assert_eq!(
+            (3, Err::<u64, _>(anyhow!("no cycle found"))).into_day_result(),
+            solution
+        );
+        assert_ne!((3, 4).into_day_result(), solution);
+        assert_eq!(
+            "DayResult {\n\tpart 1: 3\n\tpart 2: error: no cycle found\n}\n",
+            solution.to_string()
+        );
+        assert_eq!(1, solution.errors().count());
+        assert_eq!(Some(&Answers::from(3)), solution.answer(1));
+        assert_eq!(None, solution.answer(2));
+
+        let both = (3, 4).into_day_result();
+        assert_eq!(Some(&Answers::from(4)), both.answer(2));
+        assert_eq!(None, both.answer(0));
+        assert_eq!(None, both.answer(3));
+
+        assert_eq!(
+            (Ok::<_, anyhow::Error>(3), ()).into_day_result(),
+            (3,).into_day_result()
+        );
+        assert_eq!(
+            "DayResult {\n\tpart 1: 3\n\tpart 2: TBC\n}\n",
+            (3, ()).into_day_result().to_string()
+        );
+        assert_eq!(
+            (3, ()).into_day_result(),
+            (3, None::<u64>).into_day_result()
+        );
+        assert_eq!((3, 4).into_day_result(), (3, Some(4)).into_day_result());
+    }
+
+    #[test]
+    fn compares_answers_by_value() {
+        assert_eq!(Answers::from(5_usize), Answers::from(5_isize));
+        assert_eq!(Answers::from(-3_i8), Answers::from(BigInt::from(-3)));
+        assert_ne!(Answers::from(5_u8), Answers::from(-5_i64));
+
+        let big = BigInt::from(u128::MAX) * 7_u8;
+        assert_eq!(Answers::from(big.clone()), Answers::from(big.clone()));
+        assert_ne!(Answers::from(big.clone()), Answers::from(u128::MAX));
+        assert_eq!(
+            Answers::from(BigInt::from(u128::MAX)),
+            Answers::from(u128::MAX)
+        );
+        assert_eq!(Answers::from(big.to_string()), Answers::from(big));
+
+        // text only matches a number it prints as
+        assert_eq!(Answers::from("42"), Answers::from(42_u32));
+        assert_ne!(Answers::from("042"), Answers::from(42_u32));
+        assert_ne!(Answers::from("abc"), Answers::from(0));
+    }
+}
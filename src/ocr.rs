@@ -0,0 +1,290 @@
+use std::fmt::{Debug, Display, Formatter};
+
+use anyhow::{anyhow, bail, Context};
+
+/// The 4x6 letters most puzzles draw, e.g. 2016 day 8 & 2022 day 10
+const SMALL: &[(char, [&str; 6])] = &[
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// The 6x10 letters of 2018 day 10
+#[rustfmt::skip]
+const LARGE: &[(char, [&str; 10])] = &[
+    ('A', ["..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#"]),
+    ('B', ["#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#", "#....#", "#####."]),
+    ('C', [".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#....#", ".####."]),
+    ('E', ["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "######"]),
+    ('F', ["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+    ('G', [".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#", "#...##", ".###.#"]),
+    ('H', ["#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#", "#....#"]),
+    ('J', ["...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.", "#...#.", ".###.."]),
+    ('K', ["#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..", "#...#.", "#....#"]),
+    ('L', ["#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "######"]),
+    ('N', ["#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##", "#...##", "#....#"]),
+    ('P', ["#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+    ('R', ["#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.", "#....#", "#....#"]),
+    ('X', ["#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.", "#....#", "#....#"]),
+    ('Z', ["######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....", "#.....", "######"]),
+];
+
+fn is_lit(c: char) -> bool {
+    matches!(c, '#' | '█')
+}
+
+/// An answer drawn as letters in `#`s, kept as drawn & read back with [`Grid::decode`]
+#[derive(Clone)]
+pub struct Grid {
+    art: String,
+    /// read once when the grid is drawn, answers are compared far more often than they're built
+    decoded: Result<String, String>,
+}
+
+impl Grid {
+    pub fn new(art: impl Into<String>) -> Grid {
+        let art = art.into().trim_end_matches('\n').to_string();
+        let decoded = decode(&art).map_err(|err| format!("{err:#}"));
+        Grid { art, decoded }
+    }
+
+    /// draws the lit `(x, y)` points, offset so the top left one is at the origin
+    pub fn from_points(points: impl IntoIterator<Item = (i64, i64)>) -> Grid {
+        let points = points.into_iter().collect::<Vec<_>>();
+        let (Some(min_x), Some(min_y)) = (
+            points.iter().map(|&(x, _)| x).min(),
+            points.iter().map(|&(_, y)| y).min(),
+        ) else {
+            return Grid::new("");
+        };
+        let width = points
+            .iter()
+            .map(|&(x, _)| x - min_x + 1)
+            .max()
+            .unwrap_or(0);
+        let height = points
+            .iter()
+            .map(|&(_, y)| y - min_y + 1)
+            .max()
+            .unwrap_or(0);
+
+        let mut rows = vec![vec!['.'; width as usize]; height as usize];
+        for (x, y) in points {
+            rows[(y - min_y) as usize][(x - min_x) as usize] = '#';
+        }
+        let rows = rows
+            .into_iter()
+            .map(|row| row.into_iter().collect::<String>())
+            .collect::<Vec<_>>();
+        Grid::new(rows.join("\n"))
+    }
+
+    pub fn art(&self) -> &str {
+        &self.art
+    }
+
+    /// the letters drawn, `None` if they can't be read
+    pub fn text(&self) -> Option<&str> {
+        self.decoded.as_deref().ok()
+    }
+
+    /// the letters drawn, or why they can't be read
+    pub fn decode(&self) -> anyhow::Result<&str> {
+        self.decoded.as_deref().map_err(|err| anyhow!("{err}"))
+    }
+}
+
+/// the letters `art` draws, the font is picked by their height
+fn decode(art: &str) -> anyhow::Result<String> {
+    let rows = pixels(art);
+    match rows.len() {
+        6 => decode_with(&rows, SMALL),
+        10 => decode_with(&rows, LARGE),
+        height => bail!("no font is {height} pixels tall"),
+    }
+}
+
+/// the drawing's lit pixels without blank rows above or below, rows padded to the same width
+fn pixels(art: &str) -> Vec<Vec<bool>> {
+    let mut rows = art
+        .lines()
+        .map(|line| line.chars().map(is_lit).collect::<Vec<_>>())
+        .skip_while(|row| !row.contains(&true))
+        .collect::<Vec<_>>();
+    while rows.last().is_some_and(|row| !row.contains(&true)) {
+        rows.pop();
+    }
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+    for row in &mut rows {
+        row.resize(width, false);
+    }
+    rows
+}
+
+/// the lit columns `rows` glyphs cover, trimmed of blank columns on either side
+fn trimmed<R: AsRef<[bool]>>(rows: &[R], columns: std::ops::Range<usize>) -> Vec<Vec<bool>> {
+    let lit = |x: usize| rows.iter().any(|row| row.as_ref()[x]);
+    let start = columns.clone().find(|&x| lit(x)).unwrap_or(columns.end);
+    let end = columns.rev().find(|&x| lit(x)).map_or(start, |x| x + 1);
+    rows.iter()
+        .map(|row| row.as_ref()[start..end].to_vec())
+        .collect()
+}
+
+fn decode_with<const H: usize>(
+    rows: &[Vec<bool>],
+    font: &[(char, [&str; H])],
+) -> anyhow::Result<String> {
+    let width = rows.first().map_or(0, Vec::len);
+    let blank = |x: usize| rows.iter().all(|row| !row[x]);
+
+    let mut text = String::new();
+    let mut x = 0;
+    while x < width {
+        if blank(x) {
+            x += 1;
+            continue;
+        }
+        // letters are separated by at least one blank column
+        let end = (x..width).find(|&x| blank(x)).unwrap_or(width);
+        let letter = trimmed(rows, x..end);
+        let (c, _) = font
+            .iter()
+            .find(|(_, glyph)| {
+                let glyph = glyph
+                    .iter()
+                    .map(|row| row.chars().map(is_lit).collect::<Vec<_>>())
+                    .collect::<Vec<_>>();
+                trimmed(&glyph, 0..glyph[0].len()) == letter
+            })
+            .with_context(|| format!("unknown letter at column {x}"))?;
+        text.push(*c);
+        x = end;
+    }
+
+    Ok(text)
+}
+
+/// on its own lines, so it reads as drawn after a `part 1: ` label
+impl Display for Grid {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "\n{}", self.art)
+    }
+}
+
+impl Debug for Grid {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.decoded {
+            Ok(text) => write!(f, "Grid({text:?})"),
+            Err(_) => write!(f, "Grid({:?})", self.art),
+        }
+    }
+}
+
+/// grids that can't be read are only equal if they're drawn the same
+impl PartialEq for Grid {
+    fn eq(&self, other: &Self) -> bool {
+        match (&self.decoded, &other.decoded) {
+            (Ok(text), Ok(other_text)) => text == other_text,
+            _ => self.art == other.art,
+        }
+    }
+}
+
+impl Eq for Grid {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ocr::{Grid, LARGE, SMALL},
+        Answers,
+    };
+
+    #[test]
+    fn reads_small_letters() {
+        let grid = Grid::new(
+            "\
+.##..###..####.#..#
+#..#.#..#.#....#..#
+#..#.###..###..####
+####.#..#.#....#..#
+#..#.#..#.#....#..#
+#..#.###..####.#..#
+",
+        );
+        assert_eq!("ABEH", grid.decode().unwrap());
+
+        // margins & the 2022 day 10 block character don't matter
+        let grid =
+            Grid::new("\n\n  ███  ███\n  █..█ █..█\n  █..█ █..█\n  ███  ███\n  █.█  █\n  █..█ █\n");
+        assert_eq!("RP", grid.decode().unwrap());
+        assert_eq!(Some("RP"), grid.text());
+
+        // every glyph reads back as itself, including the narrow I
+        for (c, glyph) in SMALL {
+            assert_eq!(c.to_string(), Grid::new(glyph.join("\n")).decode().unwrap());
+        }
+        for (c, glyph) in LARGE {
+            assert_eq!(c.to_string(), Grid::new(glyph.join("\n")).decode().unwrap());
+        }
+    }
+
+    #[test]
+    fn reads_points() {
+        let x = LARGE.iter().find(|(c, _)| *c == 'X').unwrap().1;
+        let n = LARGE.iter().find(|(c, _)| *c == 'N').unwrap().1;
+        let points = [(x, 100), (n, 108)]
+            .into_iter()
+            .flat_map(|(glyph, offset)| {
+                glyph.into_iter().enumerate().flat_map(move |(y, row)| {
+                    row.char_indices()
+                        .filter(|&(_, c)| c == '#')
+                        .map(move |(x, _)| (x as i64 + offset, y as i64 - 3))
+                })
+            });
+        let grid = Grid::from_points(points);
+        assert_eq!("XN", grid.decode().unwrap());
+        assert!(grid.art().starts_with("#....#..#....#\n"), "{}", grid.art());
+    }
+
+    #[test]
+    fn compares_the_letters() {
+        let grid = Grid::new(SMALL[0].1.join("\n"));
+        assert_eq!(Answers::from(grid.clone()), Answers::from("A"));
+        assert_ne!(Answers::from(grid.clone()), Answers::from("B"));
+        assert_eq!(format!("\n{}", SMALL[0].1.join("\n")), grid.to_string());
+
+        let err = Grid::new("#.#\n.#.\n#.#").decode().unwrap_err();
+        assert_eq!("no font is 3 pixels tall", err.to_string());
+        let unreadable = Grid::new(["####"; 6].join("\n"));
+        assert_eq!(None, unreadable.text());
+        let err = unreadable.decode().unwrap_err();
+        assert_eq!("unknown letter at column 0", err.to_string());
+
+        // an unreadable drawing is an error, not text that can never match
+        let err = Answers::from(unreadable.clone()).text().unwrap_err();
+        assert_eq!(
+            "couldn't read the drawn answer: unknown letter at column 0",
+            format!("{err:#}")
+        );
+        assert_eq!(Answers::from(unreadable.clone()), Answers::from(unreadable));
+        assert_ne!(
+            Answers::from(Grid::new(["####"; 6].join("\n"))),
+            Answers::from(["####"; 6].join("\n"))
+        );
+    }
+}
@@ -0,0 +1,294 @@
+use std::fmt::{Display, Formatter};
+
+use bstr::ByteSlice;
+use nom::{error::ErrorKind, AsBytes};
+
+/// how much of the offending line is kept either side of the bad byte
+const EXCERPT_RADIUS: usize = 40;
+
+/// Malformed puzzle input, pointing at the byte that couldn't be parsed
+///
+/// Lines & columns are 1-based and columns count bytes, as inputs are ascii.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputError {
+    line: usize,
+    column: usize,
+    excerpt: String,
+    excerpt_column: usize,
+    message: String,
+}
+
+impl InputError {
+    /// `rest` is the unparsed remainder of `input`, the error points at its first byte
+    pub fn at(input: &[u8], rest: &[u8], message: impl Display) -> InputError {
+        let offset = (rest.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|&offset| offset <= input.len())
+            .unwrap_or(input.len());
+        InputError::at_offset(input, offset, message)
+    }
+
+    pub fn at_offset(input: &[u8], offset: usize, message: impl Display) -> InputError {
+        let offset = offset.min(input.len());
+        let line_start = input[..offset]
+            .iter()
+            .rposition(|&b| b == b'\n')
+            .map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .iter()
+            .position(|&b| b == b'\n')
+            .map_or(input.len(), |i| offset + i);
+        let line = &input[line_start..line_end];
+        let line = line.strip_suffix(b"\r").unwrap_or(line);
+        let column = offset - line_start;
+
+        let excerpt_start = column.saturating_sub(EXCERPT_RADIUS);
+        let excerpt_end = line.len().min(column + EXCERPT_RADIUS).max(excerpt_start);
+
+        InputError {
+            line: input[..line_start].iter().filter(|&&b| b == b'\n').count() + 1,
+            column: column + 1,
+            excerpt: String::from_utf8_lossy(&line[excerpt_start..excerpt_end]).into_owned(),
+            excerpt_column: column - excerpt_start,
+            message: message.to_string(),
+        }
+    }
+
+    /// locates a nom error, which carries the input remaining where it failed
+    pub fn nom<I>(input: &[u8], err: nom::Err<nom::error::Error<I>>) -> InputError
+    where
+        I: AsBytes,
+    {
+        match err {
+            nom::Err::Incomplete(_) => {
+                InputError::at_offset(input, input.len(), "unexpected end of input")
+            }
+            nom::Err::Error(err) | nom::Err::Failure(err) => {
+                let rest = err.input.as_bytes();
+                let message = match err.code {
+                    ErrorKind::Eof => "unexpected end of input".to_string(),
+                    code => format!("failed to parse {}", code.description()),
+                };
+                InputError::at(input, rest, message)
+            }
+        }
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn excerpt(&self) -> &str {
+        &self.excerpt
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{message} at line {line}, column {column}",
+            message = self.message,
+            line = self.line,
+            column = self.column
+        )?;
+        writeln!(f, "  {}", self.excerpt)?;
+        write!(f, "  {:>width$}", "^", width = self.excerpt_column + 1)
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// checks `input` is a rectangular grid of newline terminated rows, returning its width & height
+pub fn grid_size(input: &[u8]) -> Result<(usize, usize), InputError> {
+    grid_size_within(input, input)
+}
+
+/// [`grid_size`] for a `grid` sliced out of `input`, so errors point into the whole input
+pub fn grid_size_within(input: &[u8], grid: &[u8]) -> Result<(usize, usize), InputError> {
+    let base = (grid.as_ptr() as usize).saturating_sub(input.as_ptr() as usize);
+    let width = grid
+        .iter()
+        .position(|&b| b == b'\n')
+        .ok_or_else(|| InputError::at_offset(input, base + grid.len(), "expected a newline"))?;
+    if width == 0 {
+        return Err(InputError::at_offset(input, base, "expected a grid row"));
+    }
+
+    for (y, row) in grid.chunks(width + 1).enumerate() {
+        let offset = base + y * (width + 1);
+        if let Some(x) = row[..row.len().min(width)].iter().position(|&b| b == b'\n') {
+            return Err(InputError::at_offset(
+                input,
+                offset + x,
+                format_args!("row is shorter than the first row's {width} tiles"),
+            ));
+        }
+        if row.get(width) != Some(&b'\n') {
+            return Err(InputError::at_offset(
+                input,
+                offset + width,
+                "expected a newline",
+            ));
+        }
+    }
+
+    Ok((width, grid.len() / (width + 1)))
+}
+
+/// A cursor over the input for the hand rolled parsers, every failure is an [`InputError`]
+#[derive(Debug, Clone, Copy)]
+pub struct Scanner<'a> {
+    input: &'a [u8],
+    rest: &'a [u8],
+}
+
+impl<'a> Scanner<'a> {
+    pub fn new(input: &'a [u8]) -> Scanner<'a> {
+        Scanner { input, rest: input }
+    }
+
+    pub fn rest(&self) -> &'a [u8] {
+        self.rest
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rest.is_empty()
+    }
+
+    pub fn peek(&self) -> Option<u8> {
+        self.rest.first().copied()
+    }
+
+    pub fn next_byte(&mut self) -> Result<u8, InputError> {
+        let (&b, rest) = self
+            .rest
+            .split_first()
+            .ok_or_else(|| self.error("unexpected end of input"))?;
+        self.rest = rest;
+        Ok(b)
+    }
+
+    /// consumes `tag` if the input continues with it
+    pub fn eat(&mut self, tag: &[u8]) -> bool {
+        match self.rest.strip_prefix(tag) {
+            Some(rest) => {
+                self.rest = rest;
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn tag(&mut self, tag: &[u8]) -> Result<(), InputError> {
+        if self.eat(tag) {
+            Ok(())
+        } else {
+            Err(self.error(format_args!("expected {:?}", tag.as_bstr())))
+        }
+    }
+
+    pub fn skip(&mut self, n: usize) -> Result<&'a [u8], InputError> {
+        if n > self.rest.len() {
+            return Err(self.error("unexpected end of input"));
+        }
+        let (skipped, rest) = self.rest.split_at(n);
+        self.rest = rest;
+        Ok(skipped)
+    }
+
+    /// everything up to the next `\n`, which is consumed but not returned
+    pub fn line(&mut self) -> &'a [u8] {
+        let (line, rest) = match self.rest.iter().position(|&b| b == b'\n') {
+            Some(i) => (&self.rest[..i], &self.rest[i + 1..]),
+            None => (self.rest, &self.rest[self.rest.len()..]),
+        };
+        self.rest = rest;
+        line
+    }
+
+    /// the end of a line, or of the input if it has no trailing newline
+    pub fn newline(&mut self) -> Result<(), InputError> {
+        if self.is_empty() || self.eat(b"\n") {
+            Ok(())
+        } else {
+            Err(self.error("expected a newline"))
+        }
+    }
+
+    pub fn parse<T, P>(&mut self, mut parser: P) -> Result<T, InputError>
+    where
+        P: nom::Parser<&'a [u8], T, nom::error::Error<&'a [u8]>>,
+    {
+        let (rest, value) = parser
+            .parse(self.rest)
+            .map_err(|err| InputError::nom(self.input, err))?;
+        self.rest = rest;
+        Ok(value)
+    }
+
+    /// an error at the current position
+    pub fn error(&self, message: impl Display) -> InputError {
+        InputError::at(self.input, self.rest, message)
+    }
+
+    /// an error at `rest`, which must be a remainder of this scanner's input
+    pub fn error_at(&self, rest: &[u8], message: impl Display) -> InputError {
+        InputError::at(self.input, rest, message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::character::complete::u32 as parse_u32;
+
+    use crate::parse::{grid_size, InputError, Scanner};
+
+    #[test]
+    fn points_at_the_offending_byte() {
+        let input = b"Game 1: 3 blue\nGame 2: 4 bleu, 1 red\n";
+        let err = InputError::at_offset(input, 25, "expected a colour");
+        assert_eq!((2, 11), (err.line(), err.column()));
+        assert_eq!("Game 2: 4 bleu, 1 red", err.excerpt());
+        assert_eq!(
+            "expected a colour at line 2, column 11\n  Game 2: 4 bleu, 1 red\n            ^",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn scanner_reports_nom_and_tag_errors() {
+        let mut scanner = Scanner::new(b"12 x\n");
+        assert_eq!(12, scanner.parse(parse_u32).unwrap());
+        let err = scanner.tag(b", ").unwrap_err();
+        assert_eq!((1, 3), (err.line(), err.column()));
+
+        let mut scanner = Scanner::new(b"\nab");
+        scanner.newline().unwrap();
+        let err = scanner.parse(parse_u32).unwrap_err();
+        assert_eq!(
+            (2, 1, "failed to parse Digit"),
+            (err.line(), err.column(), err.message())
+        );
+    }
+
+    #[test]
+    fn grids_must_be_rectangular() {
+        assert_eq!((3, 2), grid_size(b"..#\n#..\n").unwrap());
+        let err = grid_size(b"..#\n#.\n...\n").unwrap_err();
+        assert_eq!((2, 3), (err.line(), err.column()));
+        let err = grid_size(b"..#\n#...\n").unwrap_err();
+        assert_eq!(
+            (2, 4, "expected a newline"),
+            (err.line(), err.column(), err.message())
+        );
+    }
+}
@@ -0,0 +1,244 @@
+use std::{
+    fmt::{Display, Formatter},
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use serde::Serialize;
+
+use crate::{
+    context::{FromContext, SolveContext},
+    Day, DayResult, IntoDayResult, IntoPart,
+};
+
+/// stages quicker than this are re-run to get a stable average
+const REPEAT_BELOW: Duration = Duration::from_millis(1);
+/// how long to keep re-running a quick stage for
+const REPEAT_FOR: Duration = Duration::from_millis(100);
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DayTimings {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+impl DayTimings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1 + self.part2
+    }
+}
+
+pub struct TimedResult {
+    pub result: DayResult,
+    pub timings: DayTimings,
+}
+
+pub fn time_day<D>(input: &str, ctx: &SolveContext) -> anyhow::Result<TimedResult>
+where
+    D: Day + FromContext,
+    D::Part1: IntoPart,
+    D::Part2: IntoPart,
+{
+    let day = D::from_context(ctx)?;
+    let (parsed, parse) = measure(|| day.parse(input))?;
+    // a failing part is timed & reported like any other
+    let (p1, part1) = measure(|| Ok(day.part1(&parsed)))?;
+    let (p2, part2) = measure(|| Ok(day.part2(&parsed)))?;
+
+    Ok(TimedResult {
+        result: (p1, p2).into_result()?,
+        timings: DayTimings {
+            parse,
+            part1,
+            part2,
+        },
+    })
+}
+
+fn measure<T>(mut stage: impl FnMut() -> anyhow::Result<T>) -> anyhow::Result<(T, Duration)> {
+    let start = Instant::now();
+    let value = stage()?;
+    let elapsed = start.elapsed();
+    if elapsed >= REPEAT_BELOW {
+        return Ok((value, elapsed));
+    }
+
+    let mut runs = 0;
+    let start = Instant::now();
+    while start.elapsed() < REPEAT_FOR {
+        black_box(stage()?);
+        runs += 1;
+    }
+
+    Ok((value, start.elapsed() / runs))
+}
+
+/// Timings for a run of days, displayed as an aligned table with a total row
+#[derive(Debug, Default)]
+pub struct TimingReport {
+    rows: Vec<(String, DayTimings)>,
+}
+
+impl TimingReport {
+    pub fn push(&mut self, day: &str, timings: DayTimings) {
+        self.rows.push((day.to_string(), timings));
+    }
+
+    pub fn total(&self) -> DayTimings {
+        self.rows
+            .iter()
+            .fold(DayTimings::default(), |total, (_, timings)| DayTimings {
+                parse: total.parse + timings.parse,
+                part1: total.part1 + timings.part1,
+                part2: total.part2 + timings.part2,
+            })
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let mut table = String::from("| day | parse | part 1 | part 2 | total |\n");
+        table.push_str("| --- | ---: | ---: | ---: | ---: |\n");
+        for [day, parse, part1, part2, total] in self.cells() {
+            table.push_str(&format!(
+                "| {day} | {parse} | {part1} | {part2} | {total} |\n"
+            ));
+        }
+        table
+    }
+
+    pub fn to_json(&self) -> anyhow::Result<String> {
+        let days = self
+            .rows
+            .iter()
+            .map(|(day, timings)| JsonTimings::new(day, timings))
+            .collect();
+        let report = JsonReport {
+            days,
+            total: JsonTimings::new("total", &self.total()),
+        };
+        Ok(serde_json::to_string_pretty(&report)?)
+    }
+
+    fn cells(&self) -> Vec<[String; 5]> {
+        let total = ("total".to_string(), self.total());
+        self.rows
+            .iter()
+            .chain([&total])
+            .map(|(day, timings)| {
+                [
+                    day.clone(),
+                    format!("{:.2?}", timings.parse),
+                    format!("{:.2?}", timings.part1),
+                    format!("{:.2?}", timings.part2),
+                    format!("{:.2?}", timings.total()),
+                ]
+            })
+            .collect()
+    }
+}
+
+impl Display for TimingReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let header = ["day", "parse", "part 1", "part 2", "total"].map(String::from);
+        let rows = [header].into_iter().chain(self.cells()).collect::<Vec<_>>();
+        let mut widths = [0; 5];
+        for row in &rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        for (i, row) in rows.iter().enumerate() {
+            // the total is set apart from the days above it
+            if i == rows.len() - 1 {
+                writeln!(f, "{}", "-".repeat(widths.iter().sum::<usize>() + 8))?;
+            }
+            write!(f, "{:<width$}", row[0], width = widths[0])?;
+            for (cell, width) in row.iter().zip(widths).skip(1) {
+                write!(f, "  {cell:>width$}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Serialize)]
+struct JsonReport<'a> {
+    days: Vec<JsonTimings<'a>>,
+    total: JsonTimings<'a>,
+}
+
+/// durations are exported in nanoseconds
+#[derive(Serialize)]
+struct JsonTimings<'a> {
+    day: &'a str,
+    parse_ns: u64,
+    part1_ns: u64,
+    part2_ns: u64,
+    total_ns: u64,
+}
+
+impl<'a> JsonTimings<'a> {
+    fn new(day: &'a str, timings: &DayTimings) -> JsonTimings<'a> {
+        JsonTimings {
+            day,
+            parse_ns: timings.parse.as_nanos() as u64,
+            part1_ns: timings.part1.as_nanos() as u64,
+            part2_ns: timings.part2.as_nanos() as u64,
+            total_ns: timings.total().as_nanos() as u64,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::timing::{DayTimings, TimingReport};
+
+    fn report() -> TimingReport {
+        let mut report = TimingReport::default();
+        report.push(
+            "day01",
+            DayTimings {
+                parse: Duration::from_micros(12),
+                part1: Duration::from_micros(3),
+                part2: Duration::from_millis(2),
+            },
+        );
+        report.push(
+            "day02",
+            DayTimings {
+                parse: Duration::from_micros(40),
+                part1: Duration::from_micros(5),
+                part2: Duration::from_micros(7),
+            },
+        );
+        report
+    }
+
+    #[test]
+    fn formats_aligned_table_with_total() {
+        let expected = "\
+day      parse  part 1  part 2    total
+day01  12.00µs  3.00µs  2.00ms   2.02ms
+day02  40.00µs  5.00µs  7.00µs  52.00µs
+---------------------------------------
+total  52.00µs  8.00µs  2.01ms   2.07ms
+";
+        assert_eq!(expected, report().to_string());
+    }
+
+    #[test]
+    fn exports_markdown_and_json() {
+        let report = report();
+        assert!(report
+            .to_markdown()
+            .ends_with("| total | 52.00µs | 8.00µs | 2.01ms | 2.07ms |\n"));
+
+        let json: serde_json::Value = serde_json::from_str(&report.to_json().unwrap()).unwrap();
+        assert_eq!(2_000_000, json["days"][0]["part2_ns"]);
+        assert_eq!(2_067_000, json["total"]["total_ns"]);
+    }
+}
@@ -1,22 +1,52 @@
 use arrayvec::ArrayVec;
-use bstr::ByteSlice;
 
-use crate::{DayResult, IntoDayResult};
+use crate::{parse::Scanner, Day, DayResult};
 
 pub fn solve(input: &str) -> anyhow::Result<DayResult> {
-    let mut p1 = 0;
-    let mut p2 = 0;
+    Day01.solve(input)
+}
+
+#[derive(Default)]
+pub struct Day01;
+
+impl Day for Day01 {
+    type Parsed<'a> = Vec<Calibration>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<'a>(&self, input: &'a str) -> anyhow::Result<Self::Parsed<'a>> {
+        let mut input = Scanner::new(input.as_bytes());
+        let mut calibrations = vec![];
+
+        while !input.is_empty() {
+            let line = input.line();
+            let (p1f, p2f) = first_bidirectional::<StateForward>(line);
+            // digits are 1-9 so a 0 means the line has no digits, spelled or otherwise
+            if p2f == 0 {
+                return Err(input.error_at(line, "expected a digit").into());
+            }
+            let (p1b, p2b) = first_bidirectional::<StateBackward>(line);
+            calibrations.push(Calibration {
+                digits: p1f * 10 + p1b,
+                spelled: p2f * 10 + p2b,
+            });
+        }
+
+        Ok(calibrations)
+    }
 
-    for line in input.as_bytes().lines() {
-        let (p1f, p2f) = first_bidirectional::<StateForward>(line);
-        let (p1b, p2b) = first_bidirectional::<StateBackward>(line);
-        let num1 = p1f * 10 + p1b;
-        let num2 = p2f * 10 + p2b;
-        p1 += num1;
-        p2 += num2;
+    fn part1(&self, calibrations: &Self::Parsed<'_>) -> anyhow::Result<Self::Part1> {
+        Ok(calibrations.iter().map(|c| c.digits).sum())
     }
 
-    (p1, p2).into_result()
+    fn part2(&self, calibrations: &Self::Parsed<'_>) -> anyhow::Result<Self::Part2> {
+        Ok(calibrations.iter().map(|c| c.spelled).sum())
+    }
+}
+
+pub struct Calibration {
+    digits: usize,
+    spelled: usize,
 }
 
 fn first_bidirectional<F>(mut line: &[u8]) -> (usize, usize)
@@ -191,19 +221,19 @@ impl Feedable for StateBackward {
 
 #[cfg(test)]
 mod tests {
-    use crate::{days::day01::solve, IntoDayResult};
+    use crate::{answers::assert_recorded, y2023::day01::solve};
 
     #[test]
     fn works_for_example() {
-        const INPUT: &str = include_str!("../../input/day01_test.txt");
+        const INPUT: &str = include_str!("../../input/2023/day01_test.txt");
         let solution = solve(INPUT).unwrap();
-        assert_eq!((209, 198).into_day_result(), solution);
+        assert_recorded(2023, "day01", "test", &solution);
     }
 
     #[test]
     fn works_for_input() {
-        const INPUT: &str = include_str!("../../input/day01.txt");
+        const INPUT: &str = include_str!("../../input/2023/day01.txt");
         let solution = solve(INPUT).unwrap();
-        assert_eq!((54_390, 54_277).into_day_result(), solution);
+        assert_recorded(2023, "day01", "real", &solution);
     }
 }
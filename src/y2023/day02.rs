@@ -0,0 +1,113 @@
+use std::cmp::max;
+
+use anyhow::Context;
+use nom::character::complete::u32 as parse_u32;
+
+use crate::{parse::Scanner, Day, DayResult};
+
+pub fn solve(input: &str) -> anyhow::Result<DayResult> {
+    Day02.solve(input)
+}
+
+#[derive(Default)]
+pub struct Day02;
+
+impl Day for Day02 {
+    type Parsed<'a> = Vec<Game>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse<'a>(&self, input: &'a str) -> anyhow::Result<Self::Parsed<'a>> {
+        let mut games = vec![];
+
+        let mut input = Scanner::new(input.as_bytes());
+
+        while !input.is_empty() {
+            input.tag(b"Game ")?;
+            let id = input.parse(parse_u32)?;
+            input.tag(b": ")?;
+
+            let mut game = Game {
+                id,
+                red: 0,
+                green: 0,
+                blue: 0,
+            };
+
+            loop {
+                let count = input.parse(parse_u32)?;
+                input.tag(b" ")?;
+                if input.eat(b"red") {
+                    game.red = max(game.red, count);
+                } else if input.eat(b"green") {
+                    game.green = max(game.green, count);
+                } else if input.eat(b"blue") {
+                    game.blue = max(game.blue, count);
+                } else {
+                    return Err(input.error("expected red, green or blue").into());
+                }
+
+                if !(input.eat(b", ") || input.eat(b"; ")) {
+                    input.newline()?;
+                    break;
+                }
+            }
+
+            games.push(game);
+        }
+
+        Ok(games)
+    }
+
+    fn part1(&self, games: &Self::Parsed<'_>) -> anyhow::Result<Self::Part1> {
+        games
+            .iter()
+            .filter(|game| game.red <= 12 && game.green <= 13 && game.blue <= 14)
+            .try_fold(0_u32, |total, game| total.checked_add(game.id))
+            .context("the sum of the game ids overflows a u32")
+    }
+
+    fn part2(&self, games: &Self::Parsed<'_>) -> anyhow::Result<Self::Part2> {
+        games
+            .iter()
+            .try_fold(0_u32, |total, game| {
+                let power = game.red.checked_mul(game.green)?.checked_mul(game.blue)?;
+                total.checked_add(power)
+            })
+            .context("the sum of the powers overflows a u32")
+    }
+}
+
+pub struct Game {
+    id: u32,
+    red: u32,
+    green: u32,
+    blue: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{answers::assert_recorded, y2023::day02::solve};
+
+    #[test]
+    fn works_for_example() {
+        const INPUT: &str = include_str!("../../input/2023/day02_test.txt");
+        let solution = solve(INPUT).unwrap();
+        assert_recorded(2023, "day02", "test", &solution);
+    }
+
+    #[test]
+    fn works_for_input() {
+        const INPUT: &str = include_str!("../../input/2023/day02.txt");
+        let solution = solve(INPUT).unwrap();
+        assert_recorded(2023, "day02", "real", &solution);
+    }
+
+    #[test]
+    fn rejects_powers_past_u32_max() {
+        let input = format!("Game 1: {} red, 2 green, 1 blue\n", u32::MAX);
+        let solution = solve(&input).unwrap();
+        assert!(solution.part1.is_ok());
+        assert!(solution.part2.is_err());
+    }
+}
@@ -0,0 +1,176 @@
+use anyhow::Context;
+use arrayvec::ArrayVec;
+use fxhash::FxHashMap;
+
+use crate::{
+    parse::{grid_size, InputError},
+    Day, DayResult,
+};
+
+macro_rules! update {
+    ($building_number:ident, $x:ident, $y:ident, $num_start:ident, $num_end:ident, $number:ident, $input:ident, $asterisks:ident, $width:ident, $part_numbers:ident) => {
+        if $building_number {
+            let mut found_symbol = false;
+            for ny in $y.checked_sub(1).unwrap_or($y)..($y + 2) {
+                for nx in $num_start.checked_sub(1).unwrap_or($num_start)..($num_end + 2) {
+                    if ny == $y && nx >= $num_start && nx <= $num_end {
+                        continue;
+                    }
+
+                    let Some(&t) = $input.get(nx + ny * ($width + 1)) else {
+                        continue;
+                    };
+                    if t == b'\n' || t == b'.' || t.is_ascii_digit() {
+                        continue;
+                    }
+
+                    if t == b'*' {
+                        let ids = $asterisks
+                            .entry(nx + ny * $width)
+                            .or_insert(ArrayVec::<Number, 3>::new());
+                        let num = Number {
+                            $num_start,
+                            $num_end,
+                            $number,
+                        };
+                        // a third number is only kept so the asterisk isn't mistaken for a gear
+                        if !ids.contains(&num) {
+                            let _ = ids.try_push(num);
+                        }
+                    }
+
+                    found_symbol = true;
+                }
+            }
+            if found_symbol {
+                $part_numbers.push($number);
+            }
+        }
+        $number = 0;
+        $building_number = false;
+    };
+}
+
+pub fn solve(input: &str) -> anyhow::Result<DayResult> {
+    Day03.solve(input)
+}
+
+#[derive(Default)]
+pub struct Day03;
+
+impl Day for Day03 {
+    type Parsed<'a> = Schematic;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<'a>(&self, input: &'a str) -> anyhow::Result<Self::Parsed<'a>> {
+        let input = input.as_bytes();
+
+        let mut part_numbers = vec![];
+
+        let (width, height) = grid_size(input)?;
+
+        let mut num_start = 0;
+        let mut num_end = 0;
+        let mut building_number = false;
+        let mut number = 0_usize;
+        let mut asterisks = FxHashMap::default();
+
+        for y in 0..height {
+            for x in 0..width {
+                let &b = input
+                    .get(x + y * (width + 1))
+                    .context("this should be a known legal coord")?;
+
+                if b.is_ascii_digit() {
+                    if !building_number {
+                        num_start = x;
+                    }
+                    building_number = true;
+                    number = number
+                        .checked_mul(10)
+                        .and_then(|number| number.checked_add((b - b'0') as usize))
+                        .ok_or_else(|| {
+                            InputError::at_offset(input, x + y * (width + 1), "number is too large")
+                        })?;
+                    num_end = x;
+                } else {
+                    update!(
+                        building_number,
+                        x,
+                        y,
+                        num_start,
+                        num_end,
+                        number,
+                        input,
+                        asterisks,
+                        width,
+                        part_numbers
+                    );
+                }
+            }
+
+            update!(
+                building_number,
+                x,
+                y,
+                num_start,
+                num_end,
+                number,
+                input,
+                asterisks,
+                width,
+                part_numbers
+            );
+        }
+
+        Ok(Schematic {
+            part_numbers,
+            asterisks,
+        })
+    }
+
+    fn part1(&self, schematic: &Self::Parsed<'_>) -> anyhow::Result<Self::Part1> {
+        Ok(schematic.part_numbers.iter().sum())
+    }
+
+    fn part2(&self, schematic: &Self::Parsed<'_>) -> anyhow::Result<Self::Part2> {
+        Ok(schematic
+            .asterisks
+            .values()
+            .filter(|number_counts| number_counts.len() == 2)
+            .map(|number_counts| number_counts.iter().map(|n| n.number).product::<usize>())
+            .sum::<usize>())
+    }
+}
+
+pub struct Schematic {
+    part_numbers: Vec<usize>,
+    asterisks: FxHashMap<usize, ArrayVec<Number, 3>>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct Number {
+    num_start: usize,
+    num_end: usize,
+    number: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{answers::assert_recorded, y2023::day03::solve};
+
+    #[test]
+    fn works_for_example() {
+        const INPUT: &str = include_str!("../../input/2023/day03_test.txt");
+        let solution = solve(INPUT).unwrap();
+        assert_recorded(2023, "day03", "test", &solution);
+    }
+
+    #[test]
+    fn works_for_input() {
+        const INPUT: &str = include_str!("../../input/2023/day03.txt");
+        let solution = solve(INPUT).unwrap();
+        assert_recorded(2023, "day03", "real", &solution);
+    }
+}
@@ -0,0 +1,141 @@
+use anyhow::Context;
+use nom::character::complete::u32 as parse_u32;
+
+use crate::{
+    parse::{InputError, Scanner},
+    Day, DayResult,
+};
+
+pub fn solve(input: &str) -> anyhow::Result<DayResult> {
+    Day04.solve(input)
+}
+
+#[derive(Default)]
+pub struct Day04;
+
+impl Day for Day04 {
+    type Parsed<'a> = Vec<usize>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse<'a>(&self, input: &'a str) -> anyhow::Result<Self::Parsed<'a>> {
+        let mut all_matches = vec![];
+
+        let mut input = Scanner::new(input.as_bytes());
+
+        while !input.is_empty() {
+            let mut bingo_set = BingoSet::default();
+
+            input.tag(b"Card")?;
+            while input.eat(b" ") {}
+            input.parse(parse_u32)?;
+            input.tag(b":")?;
+
+            while !input.eat(b" |") {
+                bingo_set.set(parse_number(&mut input)?);
+            }
+
+            let mut matches = 0;
+            while !(input.is_empty() || input.eat(b"\n")) {
+                matches += bingo_set.is_set(parse_number(&mut input)?) as usize;
+            }
+
+            all_matches.push(matches);
+        }
+
+        Ok(all_matches)
+    }
+
+    fn part1(&self, all_matches: &Self::Parsed<'_>) -> anyhow::Result<Self::Part1> {
+        all_matches
+            .iter()
+            .try_fold(0_i32, |total, &matches| {
+                let points = match matches {
+                    0 => 0,
+                    _ => 2_i32.checked_pow(u32::try_from(matches - 1).ok()?)?,
+                };
+                total.checked_add(points)
+            })
+            .context("the points overflow an i32")
+    }
+
+    fn part2(&self, all_matches: &Self::Parsed<'_>) -> anyhow::Result<Self::Part2> {
+        let mut all_cards = all_matches
+            .iter()
+            .map(|&matches| (1_i32, matches))
+            .collect::<Vec<_>>();
+
+        let mut all_cards_slice = all_cards.as_mut_slice();
+        while let [(count, matches), _all_cards_slice @ ..] = all_cards_slice {
+            all_cards_slice = _all_cards_slice;
+            for card in all_cards_slice.iter_mut().take(*matches) {
+                card.0 = card
+                    .0
+                    .checked_add(*count)
+                    .context("the card count overflows an i32")?;
+            }
+        }
+
+        all_cards
+            .into_iter()
+            .try_fold(0_i32, |total, (count, _)| total.checked_add(count))
+            .context("the card count overflows an i32")
+    }
+}
+
+// numbers are right aligned in 3 byte columns, e.g. `  6` or ` 31`
+fn parse_number(input: &mut Scanner) -> Result<u64, InputError> {
+    input.tag(b" ")?;
+    let number = input.skip(2)?;
+    match *number {
+        [b' ', digit @ b'0'..=b'9'] => Ok((digit - b'0') as u64),
+        [tens @ b'0'..=b'9', digit @ b'0'..=b'9'] => Ok(((tens - b'0') * 10 + digit - b'0') as u64),
+        _ => Err(input.error_at(number, "expected a number below 100")),
+    }
+}
+
+#[derive(Default)]
+struct BingoSet {
+    set: u128,
+}
+
+impl BingoSet {
+    fn set(&mut self, val: u64) {
+        self.set |= 1_u128 << val
+    }
+
+    fn is_set(&self, val: u64) -> bool {
+        self.set & (1_u128 << val) != 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{answers::assert_recorded, y2023::day04::solve};
+
+    #[test]
+    fn works_for_example() {
+        const INPUT: &str = include_str!("../../input/2023/day04_test.txt");
+        let solution = solve(INPUT).unwrap();
+        assert_recorded(2023, "day04", "test", &solution);
+    }
+
+    #[test]
+    fn works_for_input() {
+        const INPUT: &str = include_str!("../../input/2023/day04.txt");
+        let solution = solve(INPUT).unwrap();
+        assert_recorded(2023, "day04", "real", &solution);
+    }
+
+    #[test]
+    fn rejects_counts_past_i32_max() {
+        let card = "Card 1:  1  2  3  4  5 |  1  1  1  1  1  1  1  1  1  1  1  1  1  1  1  1\n";
+        let input = card.repeat(40);
+        let solution = solve(&input).unwrap();
+        assert!(solution.part1.is_ok());
+        assert!(solution.part2.is_err());
+
+        let input = card.replace("  1\n", &"  1".repeat(32)) + "\n";
+        assert!(solve(&input).unwrap().part1.is_err());
+    }
+}
@@ -0,0 +1,246 @@
+use std::cmp::{max, min};
+
+use anyhow::Context;
+use arrayvec::ArrayVec;
+use itertools::Itertools;
+use nom::{bytes::complete::tag, character::complete::u64 as parse_u64, multi::separated_list1};
+
+use crate::{
+    parse::{InputError, Scanner},
+    Day, DayResult,
+};
+
+pub fn solve(input: &str) -> anyhow::Result<DayResult> {
+    Day05.solve(input)
+}
+
+#[derive(Default)]
+pub struct Day05;
+
+impl Day for Day05 {
+    type Parsed<'a> = Almanac;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse<'a>(&self, input: &'a str) -> anyhow::Result<Self::Parsed<'a>> {
+        let mut input = Scanner::new(input.as_bytes());
+        input.tag(b"seeds: ")?;
+        let seeds = input.parse(separated_list1(tag(" "), parse_u64))?;
+        input.newline()?;
+
+        let mut block_maps = ArrayVec::<BlockMap, 7>::new();
+        for header in BLOCK_MAP_HEADERS {
+            input.tag(b"\n")?;
+            input.tag(header)?;
+            input.tag(b"\n")?;
+            block_maps.push(parse_block_map(&mut input)?);
+        }
+        if !input.is_empty() {
+            return Err(input.error("expected the end of the almanac").into());
+        }
+
+        Ok(Almanac { seeds, block_maps })
+    }
+
+    fn part1(&self, almanac: &Self::Parsed<'_>) -> anyhow::Result<Self::Part1> {
+        almanac
+            .seeds
+            .iter()
+            .map(|&seed| {
+                almanac
+                    .block_maps
+                    .iter()
+                    .fold(seed, |seed, b| b.apply(seed))
+            })
+            .min()
+            .context("expected at least 1 seed")
+    }
+
+    fn part2(&self, almanac: &Self::Parsed<'_>) -> anyhow::Result<Self::Part2> {
+        solve_p2(&almanac.seeds, &almanac.block_maps)?.context("there shoule be seeds")
+    }
+}
+
+pub struct Almanac {
+    seeds: Vec<u64>,
+    block_maps: ArrayVec<BlockMap, 7>,
+}
+
+fn solve_p2(seeds: &[u64], block_maps: &[BlockMap]) -> anyhow::Result<Option<u64>> {
+    let ranges = seeds.iter().tuples().map(|(&seed, &len)| {
+        let end = seed
+            .checked_add(len)
+            .with_context(|| format!("seed range {seed} + {len} runs past u64::MAX"))?;
+        Ok(solve_p2_2(block_maps, seed, end))
+    });
+    itertools::process_results(ranges, |lowest| lowest.flatten().min())
+}
+
+macro_rules! subsolve_and_update {
+    ($rest:tt, $new_min:tt, $new_max:tt, $res:tt) => {
+        if let Some(new_res) = solve_p2_2($rest, $new_min, $new_max) {
+            $res = match $res {
+                Some(res) => Some(min(res, new_res)),
+                None => Some(new_res),
+            }
+        }
+    };
+}
+
+fn solve_p2_2(block_maps: &[BlockMap], curr_min: u64, curr_max: u64) -> Option<u64> {
+    if let [bm, rest @ ..] = block_maps {
+        let mut res = None;
+
+        let fmap = &bm.mappings[0];
+        let new_min = curr_min;
+        let new_max = min(curr_max, fmap.src_start);
+        if new_min < new_max {
+            subsolve_and_update!(rest, new_min, new_max, res);
+        }
+
+        let lmap = &bm.mappings[bm.mappings.len() - 1];
+        let lmin = lmap.src_start + lmap.width;
+        let new_min = max(curr_min, lmin);
+        let new_max = curr_max;
+        if new_min < new_max {
+            subsolve_and_update!(rest, new_min, new_max, res);
+        }
+
+        for m in &bm.mappings {
+            let start = m.src_start;
+            if start > curr_max {
+                break;
+            }
+            let end = m.src_start + m.width;
+            let clamped_min = max(curr_min, start);
+            let clamped_max = min(curr_max, end);
+            if clamped_min < clamped_max {
+                let new_min = clamped_min - m.src_start + m.dst_start;
+                let new_max = clamped_max - m.src_start + m.dst_start;
+                subsolve_and_update!(rest, new_min, new_max, res);
+            }
+        }
+
+        for (a, b) in bm.mappings.iter().tuple_windows() {
+            let start = a.src_start + a.width;
+            if start > curr_max {
+                break;
+            }
+            let end = b.src_start;
+            let new_min = max(curr_min, start);
+            let new_max = min(curr_max, end);
+            if new_min < new_max {
+                subsolve_and_update!(rest, new_min, new_max, res);
+            }
+        }
+
+        res
+    } else {
+        Some(curr_min)
+    }
+}
+
+const BLOCK_MAP_HEADERS: [&[u8]; 7] = [
+    b"seed-to-soil map:",
+    b"soil-to-fertilizer map:",
+    b"fertilizer-to-water map:",
+    b"water-to-light map:",
+    b"light-to-temperature map:",
+    b"temperature-to-humidity map:",
+    b"humidity-to-location map:",
+];
+
+fn parse_block_map(input: &mut Scanner) -> Result<BlockMap, InputError> {
+    let mut mappings = vec![];
+    while !(input.is_empty() || input.peek() == Some(b'\n')) {
+        let dst_start = input.parse(parse_u64)?;
+        input.tag(b" ")?;
+        let src_start = input.parse(parse_u64)?;
+        input.tag(b" ")?;
+        let at = input.rest();
+        let width = input.parse(parse_u64)?;
+        // every end the solver computes fits once both of these do
+        if src_start.checked_add(width).is_none() || dst_start.checked_add(width).is_none() {
+            return Err(input.error_at(at, "expected the mapping to end before u64::MAX"));
+        }
+        input.newline()?;
+        mappings.push(Mapping {
+            src_start,
+            dst_start,
+            width,
+        });
+    }
+    if mappings.is_empty() {
+        return Err(input.error("expected at least 1 mapping"));
+    }
+    mappings.sort_unstable_by_key(|m| m.src_start);
+    Ok(BlockMap { mappings })
+}
+
+#[derive(Debug)]
+struct BlockMap {
+    mappings: Vec<Mapping>,
+}
+
+impl BlockMap {
+    fn apply(&self, val: u64) -> u64 {
+        for mapping in &self.mappings {
+            if let Some(val2) = mapping.map(val) {
+                return val2;
+            }
+        }
+        val
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Mapping {
+    src_start: u64,
+    dst_start: u64,
+    width: u64,
+}
+
+impl Mapping {
+    fn map(self, val: u64) -> Option<u64> {
+        let r = self.src_start..=(self.src_start + self.width);
+        if r.contains(&val) {
+            let n = val - self.src_start;
+            Some(self.dst_start + n)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{answers::assert_recorded, y2023::day05::solve};
+
+    #[test]
+    fn works_for_example() {
+        const INPUT: &str = include_str!("../../input/2023/day05_test.txt");
+        let solution = solve(INPUT).unwrap();
+        assert_recorded(2023, "day05", "test", &solution);
+    }
+
+    #[test]
+    fn works_for_input() {
+        const INPUT: &str = include_str!("../../input/2023/day05.txt");
+        let solution = solve(INPUT).unwrap();
+        assert_recorded(2023, "day05", "real", &solution);
+    }
+
+    #[test]
+    fn rejects_ranges_past_u64_max() {
+        const INPUT: &str = include_str!("../../input/2023/day05_test.txt");
+        let (_, maps) = INPUT.split_once('\n').unwrap();
+        let input = format!("seeds: {} 1\n{maps}", u64::MAX);
+        let solution = solve(&input).unwrap();
+        assert!(solution.part1.is_ok());
+        assert!(solution.part2.is_err());
+
+        let input = INPUT.replace("50 98 2", &format!("50 98 {}", u64::MAX));
+        let err = solve(&input).unwrap_err();
+        assert!(err.to_string().contains("before u64::MAX"), "{err}");
+    }
+}
@@ -0,0 +1,137 @@
+use arrayvec::ArrayVec;
+use nom::character::complete::digit1;
+
+use crate::{
+    parse::{InputError, Scanner},
+    Day, DayResult,
+};
+
+pub fn solve(input: &str) -> anyhow::Result<DayResult> {
+    Day06.solve(input)
+}
+
+#[derive(Default)]
+pub struct Day06;
+
+impl Day for Day06 {
+    type Parsed<'a> = Races;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse<'a>(&self, input: &'a str) -> anyhow::Result<Self::Parsed<'a>> {
+        let mut input = Scanner::new(input.as_bytes());
+        input.tag(b"Time:")?;
+        let (times, bigger_time) = parse_numbers(&mut input)?;
+        let distances_start = input.rest();
+        input.tag(b"Distance:")?;
+        let (distances, bigger_dist) = parse_numbers(&mut input)?;
+
+        if times.len() != distances.len() {
+            return Err(input
+                .error_at(distances_start, "expected a distance for every time")
+                .into());
+        }
+
+        Ok(Races {
+            times,
+            distances,
+            bigger_time,
+            bigger_dist,
+        })
+    }
+
+    fn part1(&self, races: &Self::Parsed<'_>) -> anyhow::Result<Self::Part1> {
+        Ok(races
+            .times
+            .iter()
+            .zip(races.distances.iter())
+            .map(|(&time, &dist)| race(time, dist))
+            .product())
+    }
+
+    fn part2(&self, races: &Self::Parsed<'_>) -> anyhow::Result<Self::Part2> {
+        Ok(race(races.bigger_time, races.bigger_dist))
+    }
+}
+
+pub struct Races {
+    times: ArrayVec<u64, 4>,
+    distances: ArrayVec<u64, 4>,
+    bigger_time: u64,
+    bigger_dist: u64,
+}
+
+fn race(time: u64, distance: u64) -> u64 {
+    let beats_record = |hold: u64| hold as u128 * (time - hold) as u128 > distance as u128;
+
+    // holding for half the time goes furthest, if that loses there is no way to win
+    let best = time / 2;
+    if !beats_record(best) {
+        return 0;
+    }
+
+    // binary search for the shortest winning hold, the winning holds are symmetric around the best
+    let mut start = 0;
+    let mut end = best;
+    while start < end {
+        let mid = start + (end - start) / 2;
+        if beats_record(mid) {
+            end = mid;
+        } else {
+            start = mid + 1;
+        }
+    }
+
+    time - start * 2 + 1
+}
+
+fn parse_numbers(input: &mut Scanner) -> Result<(ArrayVec<u64, 4>, u64), InputError> {
+    let mut res = ArrayVec::new();
+    let mut big_num = 0_u64;
+
+    loop {
+        while input.eat(b" ") {}
+        if input.is_empty() || input.eat(b"\n") {
+            break;
+        }
+
+        let digits = input.parse(digit1)?;
+        let too_large = || input.error_at(digits, "number is too large");
+        let mut num = 0_u64;
+        for &b in digits {
+            let digit = (b - b'0') as u64;
+            num = num
+                .checked_mul(10)
+                .and_then(|num| num.checked_add(digit))
+                .ok_or_else(too_large)?;
+            big_num = big_num
+                .checked_mul(10)
+                .and_then(|num| num.checked_add(digit))
+                .ok_or_else(too_large)?;
+        }
+
+        res.try_push(num)
+            .map_err(|_| input.error_at(digits, "expected at most 4 races"))?;
+    }
+
+    Ok((res, big_num))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{answers::assert_recorded, y2023::day06::solve};
+
+    #[test]
+    fn works_for_example() {
+        const INPUT: &str = include_str!("../../input/2023/day06_test.txt");
+        let solution = solve(INPUT).unwrap();
+        assert_recorded(2023, "day06", "test", &solution);
+    }
+
+    #[test]
+    fn works_for_input() {
+        const INPUT: &str = include_str!("../../input/2023/day06.txt");
+        let solution = solve(INPUT).unwrap();
+        assert_recorded(2023, "day06", "real", &solution);
+    }
+}
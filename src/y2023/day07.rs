@@ -1,59 +1,104 @@
 use std::cmp::{Ord, Ordering};
 
-use crate::{DayResult, IntoDayResult};
+use anyhow::Context;
+use nom::character::complete::u64 as parse_u64;
+
+use crate::{
+    parse::{InputError, Scanner},
+    Day, DayResult,
+};
 
 pub fn solve(input: &str) -> anyhow::Result<DayResult> {
-    let mut hands = vec![];
+    Day07.solve(input)
+}
+
+#[derive(Default)]
+pub struct Day07;
+
+impl Day for Day07 {
+    type Parsed<'a> = Vec<Hands>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<'a>(&self, input: &'a str) -> anyhow::Result<Self::Parsed<'a>> {
+        let mut hands = vec![];
+
+        let mut input = Scanner::new(input.as_bytes());
+        while !input.is_empty() {
+            let (hand, hand_joker, bet) = parse_hand(&mut input)?;
+            hands.push(Hands {
+                hand,
+                hand_joker,
+                bet,
+            });
+        }
 
-    let mut input = input.as_bytes();
-    while !input.is_empty() {
-        let (_input, hand, hand_joker, bet) = parse_hand(input);
-        input = _input;
-        hands.push((hand, hand_joker, bet));
+        Ok(hands)
     }
 
-    hands.sort_unstable_by(|(a, _, _), (b, _, _)| Ord::cmp(&a, &b));
-    let p1: usize = hands.iter().zip(1..).map(|(&(_, _, b), i)| b * i).sum();
+    fn part1(&self, hands: &Self::Parsed<'_>) -> anyhow::Result<Self::Part1> {
+        let mut hands = hands.clone();
+        hands.sort_unstable_by(|a, b| Ord::cmp(&a.hand, &b.hand));
+        total_winnings(&hands)
+    }
 
-    hands.sort_unstable_by(|&(_, a, _), &(_, b, _)| Ord::cmp(&a, &b));
-    let p2: usize = hands.iter().zip(1..).map(|(&(_, _, b), i)| b * i).sum();
+    fn part2(&self, hands: &Self::Parsed<'_>) -> anyhow::Result<Self::Part2> {
+        let mut hands = hands.clone();
+        hands.sort_unstable_by(|a, b| Ord::cmp(&a.hand_joker, &b.hand_joker));
+        total_winnings(&hands)
+    }
+}
 
-    (p1, p2).into_result()
+/// each bet times its hand's rank in the already sorted `hands`
+fn total_winnings(hands: &[Hands]) -> anyhow::Result<usize> {
+    hands
+        .iter()
+        .zip(1..)
+        .try_fold(0_usize, |total, (h, rank)| {
+            total.checked_add(h.bet.checked_mul(rank)?)
+        })
+        .context("the total winnings overflow a usize")
 }
 
-const fn table() -> [u8; 64] {
-    let mut table = [0; 64];
+#[derive(Clone, Copy)]
+pub struct Hands {
+    hand: Hand,
+    hand_joker: HandJoker,
+    bet: usize,
+}
+
+// maps a card's byte to its power + 1, leaving 0 for anything that isn't a card
+const fn table() -> [u8; 256] {
+    let mut table = [0; 256];
     const MAPPING: [u8; 13] = [
         b'2', b'3', b'4', b'5', b'6', b'7', b'8', b'9', b'T', b'J', b'Q', b'K', b'A',
     ];
 
     let mut i = 0;
     while i < MAPPING.len() {
-        table[(MAPPING[i] - b'0') as usize] = i as u8;
+        table[MAPPING[i] as usize] = i as u8 + 1;
         i += 1;
     }
 
     table
 }
 
-fn parse_hand(input: &[u8]) -> (&[u8], Hand, HandJoker, usize) {
-    const LOOKUP: [u8; 64] = table();
-    let mut raw_cards = [0; 5];
+fn parse_hand(input: &mut Scanner) -> Result<(Hand, HandJoker, usize), InputError> {
+    const LOOKUP: [u8; 256] = table();
     let mut cards = [0; 13];
     let mut card_powers = [0; 5];
-    for (i, &c) in input[0..5].iter().enumerate() {
-        raw_cards[i] = c;
-        let power = LOOKUP[(c - b'0') as usize];
+    let raw_cards = input.skip(5)?;
+    for (i, &c) in raw_cards.iter().enumerate() {
+        let power = LOOKUP[c as usize]
+            .checked_sub(1)
+            .ok_or_else(|| input.error_at(&raw_cards[i..], "expected a card"))?;
         card_powers[i] = power;
         cards[power as usize] += 1;
     }
 
-    let mut input = &input[6..];
-    let mut num = 0;
-    while input[0] != b'\n' {
-        num = num * 10 + (input[0] - b'0') as usize;
-        input = &input[1..];
-    }
+    input.tag(b" ")?;
+    let bet = input.parse(parse_u64)? as usize;
+    input.newline()?;
 
     let hand = Hand {
         card_powers,
@@ -61,7 +106,7 @@ fn parse_hand(input: &[u8]) -> (&[u8], Hand, HandJoker, usize) {
     };
     let hand_joker = HandJoker::from((hand.card_powers, cards));
 
-    (&input[1..], hand, hand_joker, num)
+    Ok((hand, hand_joker, bet))
 }
 
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -238,19 +283,27 @@ impl PartialOrd for HandJoker {
 
 #[cfg(test)]
 mod tests {
-    use crate::{days::day07::solve, IntoDayResult};
+    use crate::{answers::assert_recorded, y2023::day07::solve};
 
     #[test]
     fn works_for_example() {
-        const INPUT: &str = include_str!("../../input/day07_test.txt");
+        const INPUT: &str = include_str!("../../input/2023/day07_test.txt");
         let solution = solve(INPUT).unwrap();
-        assert_eq!((6_440, 5_905).into_day_result(), solution);
+        assert_recorded(2023, "day07", "test", &solution);
     }
 
     #[test]
     fn works_for_input() {
-        const INPUT: &str = include_str!("../../input/day07.txt");
+        const INPUT: &str = include_str!("../../input/2023/day07.txt");
         let solution = solve(INPUT).unwrap();
-        assert_eq!((251_136_060, 249_400_220).into_day_result(), solution);
+        assert_recorded(2023, "day07", "real", &solution);
+    }
+
+    #[test]
+    fn rejects_winnings_past_usize_max() {
+        let input = format!("32T3K {}\nT55J5 684\n", u64::MAX);
+        let solution = solve(&input).unwrap();
+        assert!(solution.part1.is_err());
+        assert!(solution.part2.is_err());
     }
 }
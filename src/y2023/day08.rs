@@ -0,0 +1,225 @@
+use std::collections::hash_map::Entry;
+
+use anyhow::{bail, Context};
+use bstr::ByteSlice;
+use fxhash::FxHashMap;
+use num::BigInt;
+
+use crate::{
+    context::{FromContext, SolveContext},
+    parse::{InputError, Scanner},
+    Day, DayResult,
+};
+
+pub fn solve(input: &str, ctx: &SolveContext) -> anyhow::Result<DayResult> {
+    Day08::from_context(ctx)?.solve(input)
+}
+
+pub struct Day08 {
+    pub skip_part1: bool,
+}
+
+impl FromContext for Day08 {
+    fn from_context(ctx: &SolveContext) -> anyhow::Result<Self> {
+        Ok(Day08 {
+            skip_part1: ctx.param_or("skip_part1", false)?,
+        })
+    }
+}
+
+impl Day for Day08 {
+    type Parsed<'a> = Network<'a>;
+    type Part1 = Option<usize>;
+    type Part2 = BigInt;
+
+    fn parse<'a>(&self, input: &'a str) -> anyhow::Result<Self::Parsed<'a>> {
+        let mut input = Scanner::new(input.as_bytes());
+        let instructions = input.line();
+        if instructions.is_empty() {
+            return Err(input.error_at(instructions, "expected instructions").into());
+        }
+        if let Some(i) = instructions.iter().position(|&b| b != b'L' && b != b'R') {
+            return Err(input.error_at(&instructions[i..], "expected L or R").into());
+        }
+        input.tag(b"\n")?;
+
+        let mut raw_nodes = Vec::with_capacity(input.rest().len() / 17);
+
+        while !input.is_empty() {
+            let val = parse_node_name(&mut input)?;
+            input.tag(b" = (")?;
+            let left = parse_node_name(&mut input)?;
+            input.tag(b", ")?;
+            let right = parse_node_name(&mut input)?;
+            input.tag(b")")?;
+            input.newline()?;
+
+            raw_nodes.push(NodeRaw { val, left, right });
+        }
+
+        let indices = raw_nodes
+            .iter()
+            .enumerate()
+            .map(|(i, raw_node)| (raw_node.val, i))
+            .collect::<FxHashMap<_, _>>();
+
+        let nodes = raw_nodes
+            .iter()
+            .map(|raw_node| {
+                let left = *indices
+                    .get(raw_node.left)
+                    .ok_or_else(|| input.error_at(raw_node.left, "unknown node"))?;
+                let right = *indices
+                    .get(raw_node.right)
+                    .ok_or_else(|| input.error_at(raw_node.right, "unknown node"))?;
+                Ok(Node {
+                    val: raw_node.val,
+                    left,
+                    right,
+                })
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        Ok(Network {
+            instructions,
+            nodes,
+            indices,
+        })
+    }
+
+    fn part1(&self, network: &Self::Parsed<'_>) -> anyhow::Result<Self::Part1> {
+        if self.skip_part1 {
+            return Ok(None);
+        }
+
+        let mut p1 = 0;
+        let instructions_iter = network.instructions.iter().cycle();
+        let mut curr = *network
+            .indices
+            .get(b"AAA".as_slice())
+            .context("there should be a start node")?;
+        let end = *network
+            .indices
+            .get(b"ZZZ".as_slice())
+            .context("there should be an end node")?;
+        for &instruction in instructions_iter {
+            curr = network.step(curr, instruction);
+            p1 += 1;
+            if curr == end {
+                break;
+            }
+            if p1 > network.max_steps() {
+                bail!("ZZZ is unreachable from AAA");
+            }
+        }
+
+        Ok(Some(p1))
+    }
+
+    fn part2(&self, network: &Self::Parsed<'_>) -> anyhow::Result<Self::Part2> {
+        let mut cache = FxHashMap::default();
+        network
+            .nodes
+            .iter()
+            .enumerate()
+            .filter_map(|(i, node)| node.val.ends_with(b"A").then_some(i))
+            .map(|start| to_z_loop(network, start, &mut cache).map(BigInt::from))
+            .reduce(|a, b| Ok(num::integer::lcm(a?, b?)))
+            .context("there should be a start node")?
+    }
+}
+
+fn to_z_loop(
+    network: &Network,
+    start: usize,
+    cache: &mut FxHashMap<usize, usize>,
+) -> anyhow::Result<usize> {
+    cache.clear();
+    let instructions_iter = network.instructions.iter().cycle();
+    let mut curr = start;
+    for (i, &instruction) in instructions_iter.enumerate() {
+        if network.nodes[curr].val[2] == b'Z' {
+            match cache.entry(curr) {
+                Entry::Occupied(entry) => {
+                    let last_seen_at = *entry.get();
+                    return Ok(last_seen_at);
+                }
+                Entry::Vacant(v) => v.insert(i),
+            };
+        }
+        curr = network.step(curr, instruction);
+        if i > network.max_steps() * 2 {
+            break;
+        }
+    }
+
+    bail!(
+        "no loop through a Z node from {}",
+        network.nodes[start].val.as_bstr()
+    )
+}
+
+pub struct Network<'a> {
+    instructions: &'a [u8],
+    nodes: Vec<Node<'a>>,
+    indices: FxHashMap<&'a [u8], usize>,
+}
+
+impl Network<'_> {
+    // after this many steps every (node, instruction) pair has been visited
+    fn max_steps(&self) -> usize {
+        self.nodes.len() * self.instructions.len()
+    }
+
+    fn step(&self, curr: usize, instruction: u8) -> usize {
+        let node = &self.nodes[curr];
+        if instruction == b'L' {
+            node.left
+        } else {
+            node.right
+        }
+    }
+}
+
+fn parse_node_name<'a>(input: &mut Scanner<'a>) -> Result<&'a [u8], InputError> {
+    let name = input.skip(3)?;
+    if let Some(i) = name.iter().position(|b| !b.is_ascii_alphanumeric()) {
+        return Err(input.error_at(&name[i..], "expected a node name"));
+    }
+    Ok(name)
+}
+
+#[derive(Debug)]
+struct NodeRaw<'a> {
+    val: &'a [u8],
+    left: &'a [u8],
+    right: &'a [u8],
+}
+
+#[derive(Debug)]
+struct Node<'a> {
+    val: &'a [u8],
+    left: usize,
+    right: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{answers::assert_recorded, context::SolveContext, y2023::day08::solve};
+
+    #[test]
+    fn works_for_example() {
+        const INPUT: &str = include_str!("../../input/2023/day08_test.txt");
+        const PARAMS: &str = include_str!("../../input/2023/day08_test.toml");
+        let ctx = SolveContext::from_sidecar(PARAMS).unwrap();
+        let solution = solve(INPUT, &ctx).unwrap();
+        assert_recorded(2023, "day08", "test", &solution);
+    }
+
+    #[test]
+    fn works_for_input() {
+        const INPUT: &str = include_str!("../../input/2023/day08.txt");
+        let solution = solve(INPUT, &SolveContext::default()).unwrap();
+        assert_recorded(2023, "day08", "real", &solution);
+    }
+}
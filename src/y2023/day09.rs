@@ -0,0 +1,115 @@
+use anyhow::Context;
+use nom::{bytes::complete::tag, character::complete::i64 as parse_i64, multi::separated_list1};
+
+use crate::{parse::Scanner, Day, DayResult};
+
+pub fn solve(input: &str) -> anyhow::Result<DayResult> {
+    Day09.solve(input)
+}
+
+#[derive(Default)]
+pub struct Day09;
+
+impl Day for Day09 {
+    type Parsed<'a> = Vec<Vec<isize>>;
+    type Part1 = isize;
+    type Part2 = isize;
+
+    fn parse<'a>(&self, input: &'a str) -> anyhow::Result<Self::Parsed<'a>> {
+        let mut sequences = vec![];
+
+        let mut input = Scanner::new(input.as_bytes());
+
+        while !input.is_empty() {
+            let sequence = input.parse(separated_list1(tag(" "), parse_i64))?;
+            input.newline()?;
+            sequences.push(sequence.into_iter().map(|n| n as isize).collect());
+        }
+
+        Ok(sequences)
+    }
+
+    fn part1(&self, sequences: &Self::Parsed<'_>) -> anyhow::Result<Self::Part1> {
+        let mut triangle = Vec::new();
+        sequences
+            .iter()
+            .map(|sequence| Ok(extrapolate(sequence, &mut triangle)?.0))
+            .sum()
+    }
+
+    fn part2(&self, sequences: &Self::Parsed<'_>) -> anyhow::Result<Self::Part2> {
+        let mut triangle = Vec::new();
+        sequences
+            .iter()
+            .map(|sequence| Ok(extrapolate(sequence, &mut triangle)?.1))
+            .sum()
+    }
+}
+
+fn extrapolate(sequence: &[isize], triangle: &mut Vec<isize>) -> anyhow::Result<(isize, isize)> {
+    triangle.clear();
+    triangle.extend_from_slice(sequence);
+
+    let mut layers = 1;
+
+    loop {
+        let mut final_row = true;
+        let r = range(layers, layers, triangle.len());
+
+        for a in r.start..(r.end - 1) {
+            let val = triangle[a + 1]
+                .checked_sub(triangle[a])
+                .context("differences overflowed")?;
+            final_row &= val == 0;
+            triangle.push(val);
+        }
+
+        layers += 1;
+
+        if final_row {
+            break;
+        }
+    }
+
+    (1..layers)
+        .rev()
+        .try_fold((0, 0), |(p1_end, p2_front), layer| {
+            let row = &triangle[range(layers, layer, triangle.len())];
+            Some((
+                row[row.len() - 1].checked_add(p1_end)?,
+                row[0].checked_sub(p2_front)?,
+            ))
+        })
+        .context("extrapolation overflowed")
+}
+
+fn triangle(n: usize) -> usize {
+    (n * (n - 1)) / 2
+}
+
+fn range(layers: usize, row: usize, len: usize) -> std::ops::Range<usize> {
+    let t = triangle(layers);
+    let common = (len - t) / layers;
+    let extra = layers - row;
+    let start = common * (row - 1) + (t - triangle(layers - row + 1));
+    start..(start + common + extra)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{answers::assert_recorded, y2023::day09::solve};
+
+    #[test]
+    fn works_for_example() {
+        const INPUT: &str = include_str!("../../input/2023/day09_test.txt");
+        let solution = solve(INPUT).unwrap();
+        assert_recorded(2023, "day09", "test", &solution);
+    }
+
+    #[test]
+    fn works_for_input() {
+        const INPUT: &str = include_str!("../../input/2023/day09.txt");
+        let solution = solve(INPUT).unwrap();
+        assert_recorded(2023, "day09", "real", &solution);
+    }
+}
@@ -1,51 +1,132 @@
 use std::ops::ControlFlow;
 
-use anyhow::Context;
+use anyhow::{bail, Context};
 use fxhash::FxHashSet;
 use strum::EnumIter;
 use strum::IntoEnumIterator;
 
-use crate::{DayResult, IntoDayResult};
+use crate::{
+    parse::{grid_size, InputError},
+    Day, DayResult,
+};
 
 pub fn solve(input: &str) -> anyhow::Result<DayResult> {
-    let input = input.as_bytes();
-    let width = input
-        .iter()
-        .position(|&b| b == b'\n')
-        .context("failed to find newline")?;
-    let world = TileMap {
-        raw: input,
-        width: width + 1,
-    };
+    Day10.solve(input)
+}
+
+#[derive(Default)]
+pub struct Day10;
+
+impl Day for Day10 {
+    type Parsed<'a> = Maze<'a>;
+    type Part1 = i32;
+    type Part2 = usize;
+
+    fn parse<'a>(&self, input: &'a str) -> anyhow::Result<Self::Parsed<'a>> {
+        let input = input.as_bytes();
+        let (width, _) = grid_size(input)?;
+        let world = TileMap {
+            raw: input,
+            width: width + 1,
+        };
+
+        let mut start = None;
+        for (i, &b) in input.iter().enumerate() {
+            match b {
+                b'|' | b'-' | b'L' | b'J' | b'7' | b'F' | b'.' | b'\n' => {}
+                b'S' if start.is_none() => start = Some((i % (width + 1), i / (width + 1))),
+                b'S' => return Err(InputError::at_offset(input, i, "found a second start").into()),
+                _ => return Err(InputError::at_offset(input, i, "expected a tile").into()),
+            }
+        }
+        let start = start.context("there should be a start tile")?;
+
+        Ok(Maze { world, start })
+    }
+
+    fn part1(&self, maze: &Self::Parsed<'_>) -> anyhow::Result<Self::Part1> {
+        Ok(find_loop(maze)?.furthest)
+    }
+
+    fn part2(&self, maze: &Self::Parsed<'_>) -> anyhow::Result<Self::Part2> {
+        let Maze { world, start } = maze;
+        let PipeLoop {
+            start_tile,
+            pipe_tiles,
+            ..
+        } = find_loop(maze)?;
+
+        // from the row of the start tile scan inwards until the pipes are hit & declare that side as Outer
+        // then traverse the pipe & declare the opposite side as inner
+
+        let mut x = 0;
+        let mut y = start.1;
+
+        while !pipe_tiles.contains(&(x, y)) {
+            x += 1;
+        }
+
+        let new_start = (x, y);
+
+        let mut contained = FxHashSet::default();
+
+        let tile = world.get(x, y);
+        // we can only be on a vertival or a left corner
+        // if we're on a vertical tile - read to right
+        // else if corner move right/up
+        let mut dir = find_start_dir(tile, start_tile)?;
+        loop {
+            let tile = world.get(x, y);
+            follow_pipe_and_flood_fill(
+                world,
+                tile,
+                &mut dir,
+                &mut x,
+                &mut y,
+                &pipe_tiles,
+                &mut contained,
+                start_tile,
+            )?;
+
+            if (x, y) == new_start {
+                break;
+            }
+        }
+
+        Ok(contained.len())
+    }
+}
+
+pub struct Maze<'a> {
+    world: TileMap<'a>,
+    start: (usize, usize),
+}
 
-    let start = input
-        .iter()
-        .position(|&b| b == b'S')
-        .map(|i| (i % (width + 1), i / (width + 1)))
-        .context("there should be a start tile")?;
+struct PipeLoop {
+    furthest: i32,
+    start_tile: Tile,
+    pipe_tiles: FxHashSet<(usize, usize)>,
+}
 
-    let mut start_tile = Tile::Start;
+fn find_loop(Maze { world, start }: &Maze) -> anyhow::Result<PipeLoop> {
+    let mut start_tile = None;
 
     let mut pipe_tiles = FxHashSet::default();
     let mut p1 = 0;
     'd: for mut dir in Direction::iter() {
         let start_dir = dir;
-        let (mut x, mut y) = start;
+        let (mut x, mut y) = *start;
         let mut moves = 0;
 
         pipe_tiles.clear();
         pipe_tiles.insert((x, y));
 
-        loop {
-            let Some((nx, ny)) = (match dir {
-                Direction::Up => y.checked_sub(1).map(|y| (x, y)),
-                Direction::Down => y.checked_add(1).map(|y| (x, y)),
-                Direction::Left => x.checked_sub(1).map(|x| (x, y)),
-                Direction::Right => x.checked_add(1).map(|x| (x, y)),
-            }) else {
-                break;
-            };
-
+        while let Some((nx, ny)) = match dir {
+            Direction::Up => y.checked_sub(1).map(|y| (x, y)),
+            Direction::Down => y.checked_add(1).map(|y| (x, y)),
+            Direction::Left => x.checked_sub(1).map(|x| (x, y)),
+            Direction::Right => x.checked_add(1).map(|x| (x, y)),
+        } {
             let next_tile = world.get(nx, ny);
             if let ControlFlow::Break(break_inner) = process_tile(
                 next_tile,
@@ -69,51 +150,17 @@ pub fn solve(input: &str) -> anyhow::Result<DayResult> {
         }
     }
 
-    // from the row of the start tile scan inwards until the pipes are hit & declare that side as Outer
-    // then traverse the pipe & declare the opposite side as inner
-
-    let mut x = 0;
-    let mut y = start.1;
-
-    while !pipe_tiles.contains(&(x, y)) {
-        x += 1;
-    }
-
-    let new_start = (x, y);
-
-    let mut contained = FxHashSet::default();
-
-    let tile = world.get(x, y);
-    // we can only be on a vertival or a left corner
-    // if we're on a vertical tile - read to right
-    // else if corner move right/up
-    let mut dir = find_start_dir(tile, start_tile);
-    loop {
-        let tile = world.get(x, y);
-        follow_pipe_and_flood_fill(
-            tile,
-            &mut dir,
-            &mut x,
-            &mut y,
-            &pipe_tiles,
-            &mut contained,
-            start_tile,
-        );
-
-        if (x, y) == new_start {
-            break;
-        }
-    }
-
-    let p2 = contained.len();
-
-    (p1, p2).into_result()
+    Ok(PipeLoop {
+        furthest: p1,
+        start_tile: start_tile.context("the start tile should be part of a loop")?,
+        pipe_tiles,
+    })
 }
 
 fn process_tile(
     next_tile: Tile,
     dir: &mut Direction,
-    start_tile: &mut Tile,
+    start_tile: &mut Option<Tile>,
     start_dir: Direction,
     moves: &mut i32,
     p1: &mut i32,
@@ -177,6 +224,9 @@ fn process_tile(
         }
         Tile::Start => {
             *start_tile = find_pipe_type(start_dir, *dir);
+            if start_tile.is_none() {
+                return ControlFlow::Break(true);
+            }
             *moves += 1;
             *p1 = *moves / 2;
             return ControlFlow::Break(false);
@@ -186,8 +236,8 @@ fn process_tile(
     ControlFlow::Continue(())
 }
 
-fn find_pipe_type(start_dir: Direction, dir: Direction) -> Tile {
-    match (start_dir, dir) {
+fn find_pipe_type(start_dir: Direction, dir: Direction) -> Option<Tile> {
+    let tile = match (start_dir, dir) {
         (Direction::Up, Direction::Up) => Tile::Vertical,
         (Direction::Up, Direction::Left) => Tile::CornerL,
         (Direction::Up, Direction::Right) => Tile::CornerJ,
@@ -203,11 +253,14 @@ fn find_pipe_type(start_dir: Direction, dir: Direction) -> Tile {
         (Direction::Right, Direction::Up) => Tile::CornerF,
         (Direction::Right, Direction::Down) => Tile::CornerL,
         (Direction::Right, Direction::Right) => Tile::Horizontal,
-        _ => unreachable!("lmao"),
-    }
+        _ => return None,
+    };
+    Some(tile)
 }
 
+#[allow(clippy::too_many_arguments)]
 fn follow_pipe_and_flood_fill(
+    world: &TileMap,
     tile: Tile,
     dir: &mut Direction,
     x: &mut usize,
@@ -215,30 +268,30 @@ fn follow_pipe_and_flood_fill(
     pipe_tiles: &FxHashSet<(usize, usize)>,
     contained: &mut FxHashSet<(usize, usize)>,
     start_tile: Tile,
-) {
+) -> anyhow::Result<()> {
     match tile {
         Tile::Vertical => {
             if *dir == Direction::Up {
-                flood_fill(*x + 1, *y, pipe_tiles, contained);
+                flood_fill(world, *x + 1, *y, pipe_tiles, contained)?;
                 *y -= 1;
             } else {
-                flood_fill(*x - 1, *y, pipe_tiles, contained);
+                flood_fill(world, x.wrapping_sub(1), *y, pipe_tiles, contained)?;
                 *y += 1;
             }
         }
         Tile::Horizontal => {
             if *dir == Direction::Right {
-                flood_fill(*x, *y + 1, pipe_tiles, contained);
+                flood_fill(world, *x, *y + 1, pipe_tiles, contained)?;
                 *x += 1;
             } else {
-                flood_fill(*x, *y - 1, pipe_tiles, contained);
+                flood_fill(world, *x, y.wrapping_sub(1), pipe_tiles, contained)?;
                 *x -= 1;
             }
         }
         Tile::Corner7 => {
             if *dir == Direction::Up {
-                flood_fill(*x, *y - 1, pipe_tiles, contained);
-                flood_fill(*x + 1, *y, pipe_tiles, contained);
+                flood_fill(world, *x, y.wrapping_sub(1), pipe_tiles, contained)?;
+                flood_fill(world, *x + 1, *y, pipe_tiles, contained)?;
                 *dir = Direction::Left;
                 *x -= 1;
             } else {
@@ -251,16 +304,16 @@ fn follow_pipe_and_flood_fill(
                 *dir = Direction::Left;
                 *x -= 1;
             } else {
-                flood_fill(*x, *y + 1, pipe_tiles, contained);
-                flood_fill(*x + 1, *y, pipe_tiles, contained);
+                flood_fill(world, *x, *y + 1, pipe_tiles, contained)?;
+                flood_fill(world, *x + 1, *y, pipe_tiles, contained)?;
                 *dir = Direction::Up;
                 *y -= 1;
             }
         }
         Tile::CornerL => {
             if *dir == Direction::Down {
-                flood_fill(*x, *y + 1, pipe_tiles, contained);
-                flood_fill(*x - 1, *y, pipe_tiles, contained);
+                flood_fill(world, *x, *y + 1, pipe_tiles, contained)?;
+                flood_fill(world, x.wrapping_sub(1), *y, pipe_tiles, contained)?;
                 *dir = Direction::Right;
                 *x += 1;
             } else {
@@ -273,44 +326,51 @@ fn follow_pipe_and_flood_fill(
                 *dir = Direction::Right;
                 *x += 1;
             } else {
-                flood_fill(*x, *y - 1, pipe_tiles, contained);
-                flood_fill(*x - 1, *y, pipe_tiles, contained);
+                flood_fill(world, *x, y.wrapping_sub(1), pipe_tiles, contained)?;
+                flood_fill(world, x.wrapping_sub(1), *y, pipe_tiles, contained)?;
                 *dir = Direction::Down;
                 *y += 1;
             }
         }
         Tile::Start => {
-            follow_pipe_and_flood_fill(start_tile, dir, x, y, pipe_tiles, contained, start_tile)
+            return follow_pipe_and_flood_fill(
+                world, start_tile, dir, x, y, pipe_tiles, contained, start_tile,
+            )
         }
-        _ => unreachable!("found a {tile:?} at {x} {y}"),
+        _ => bail!("found a {tile:?} in the loop at {x} {y}"),
     };
+    Ok(())
 }
 
-fn find_start_dir(tile: Tile, start_tile: Tile) -> Direction {
+fn find_start_dir(tile: Tile, start_tile: Tile) -> anyhow::Result<Direction> {
     match tile {
-        Tile::Vertical => Direction::Up,
-        Tile::CornerL => Direction::Left,
-        Tile::CornerF => Direction::Up,
-        Tile::Start => find_start_dir(start_tile, start_tile),
-        _ => unreachable!("found a {tile:?}"),
+        Tile::Vertical => Ok(Direction::Up),
+        Tile::CornerL => Ok(Direction::Left),
+        Tile::CornerF => Ok(Direction::Up),
+        Tile::Start if start_tile != Tile::Start => find_start_dir(start_tile, start_tile),
+        _ => bail!("found a {tile:?} on the outside of the loop"),
     }
 }
 
 fn flood_fill(
+    world: &TileMap,
     x: usize,
     y: usize,
     path: &FxHashSet<(usize, usize)>,
     contained: &mut FxHashSet<(usize, usize)>,
-) {
-    if path.contains(&(x, y)) {
-        return;
-    }
-    if contained.insert((x, y)) {
-        flood_fill(x - 1, y, path, contained);
-        flood_fill(x + 1, y, path, contained);
-        flood_fill(x, y - 1, path, contained);
-        flood_fill(x, y + 1, path, contained);
+) -> anyhow::Result<()> {
+    let mut stack = vec![(x, y)];
+    while let Some((x, y)) = stack.pop() {
+        if path.contains(&(x, y)) || !contained.insert((x, y)) {
+            continue;
+        }
+        // the loop can't enclose a tile on the edge of the map
+        if !world.is_interior(x, y) {
+            bail!("flood fill escaped the loop at {x} {y}");
+        }
+        stack.extend([(x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)]);
     }
+    Ok(())
 }
 
 #[derive(Debug, EnumIter, PartialEq, Clone, Copy)]
@@ -339,6 +399,11 @@ struct TileMap<'a> {
 }
 
 impl<'a> TileMap<'a> {
+    fn is_interior(&self, x: usize, y: usize) -> bool {
+        let height = self.raw.len() / self.width;
+        (1..self.width - 2).contains(&x) && (1..height - 1).contains(&y)
+    }
+
     fn get(&self, x: usize, y: usize) -> Tile {
         self.raw
             .get(x + y * self.width)
@@ -351,8 +416,8 @@ impl<'a> TileMap<'a> {
                 b'F' => Tile::CornerF,
                 b'.' => Tile::Empty,
                 b'S' => Tile::Start,
-                b'\n' => Tile::Empty,
-                _ => unreachable!("please handle me: {}", b as char),
+                // parsing has already rejected anything else
+                _ => Tile::Empty,
             })
             .unwrap_or(Tile::Empty)
     }
@@ -360,19 +425,19 @@ impl<'a> TileMap<'a> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{days::day10::solve, IntoDayResult};
+    use crate::{answers::assert_recorded, y2023::day10::solve};
 
     #[test]
     fn works_for_example() {
-        const INPUT: &str = include_str!("../../input/day10_test.txt");
+        const INPUT: &str = include_str!("../../input/2023/day10_test.txt");
         let solution = solve(INPUT).unwrap();
-        assert_eq!((80, 10).into_day_result(), solution);
+        assert_recorded(2023, "day10", "test", &solution);
     }
 
     #[test]
     fn works_for_input() {
-        const INPUT: &str = include_str!("../../input/day10.txt");
+        const INPUT: &str = include_str!("../../input/2023/day10.txt");
         let solution = solve(INPUT).unwrap();
-        assert_eq!((6_882, 491).into_day_result(), solution);
+        assert_recorded(2023, "day10", "real", &solution);
     }
 }
@@ -0,0 +1,126 @@
+use anyhow::ensure;
+use itertools::Itertools;
+
+use crate::{
+    context::{FromContext, SolveContext},
+    parse::{grid_size, InputError},
+    Day, DayResult,
+};
+
+pub fn solve(input: &str, ctx: &SolveContext) -> anyhow::Result<DayResult> {
+    Day11::from_context(ctx)?.solve(input)
+}
+
+pub struct Day11 {
+    pub multiplier: usize,
+}
+
+impl FromContext for Day11 {
+    fn from_context(ctx: &SolveContext) -> anyhow::Result<Self> {
+        let multiplier = ctx.param_or("multiplier", 1_000_000)?;
+        ensure!(multiplier > 0, "multiplier must be at least 1");
+        Ok(Day11 { multiplier })
+    }
+}
+
+impl Day for Day11 {
+    type Parsed<'a> = Universe;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<'a>(&self, input: &'a str) -> anyhow::Result<Self::Parsed<'a>> {
+        let input = input.as_bytes();
+        let (width, height) = grid_size(input)?;
+        if let Some(i) = input
+            .iter()
+            .position(|&b| !matches!(b, b'.' | b'#' | b'\n'))
+        {
+            return Err(InputError::at_offset(input, i, "expected . or #").into());
+        }
+
+        let vert_accum = (0..height)
+            .map(|h| (0..width).all(|w| input[w + h * (width + 1)] == b'.') as usize)
+            .scan(0, |acc, v| {
+                *acc += v;
+                Some(*acc)
+            })
+            .collect::<Vec<_>>();
+
+        let hori_accum = (0..width)
+            .map(|w| (0..height).all(|h| input[w + h * (width + 1)] == b'.') as usize)
+            .scan(0, |acc, v| {
+                *acc += v;
+                Some(*acc)
+            })
+            .collect::<Vec<_>>();
+
+        let galaxies = (0..height)
+            .flat_map(|h| {
+                (0..width)
+                    .filter(move |&w| input[w + h * (width + 1)] == b'#')
+                    .map(move |w| (w, h))
+            })
+            .collect::<Vec<_>>();
+
+        Ok(Universe {
+            vert_accum,
+            hori_accum,
+            galaxies,
+        })
+    }
+
+    fn part1(&self, universe: &Self::Parsed<'_>) -> anyhow::Result<Self::Part1> {
+        Ok(universe.distances(2))
+    }
+
+    fn part2(&self, universe: &Self::Parsed<'_>) -> anyhow::Result<Self::Part2> {
+        Ok(universe.distances(self.multiplier))
+    }
+}
+
+pub struct Universe {
+    vert_accum: Vec<usize>,
+    hori_accum: Vec<usize>,
+    galaxies: Vec<(usize, usize)>,
+}
+
+impl Universe {
+    fn distances(&self, multiplier: usize) -> usize {
+        let mut res = 0;
+
+        for (&(i1, j1), &(i2, j2)) in self.galaxies.iter().tuple_combinations() {
+            let dist = i1.abs_diff(i2) + j1.abs_diff(j2);
+            let i_min = std::cmp::min(i1, i2);
+            let i_max = std::cmp::max(i1, i2);
+            let j_min = std::cmp::min(j1, j2);
+            let j_max = std::cmp::max(j1, j2);
+            let additive = self.hori_accum[i_max] - self.hori_accum[i_min] + self.vert_accum[j_max]
+                - self.vert_accum[j_min];
+
+            res += dist + (additive * (multiplier - 1));
+        }
+
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{answers::assert_recorded, context::SolveContext, y2023::day11::solve};
+
+    #[test]
+    fn works_for_example() {
+        const INPUT: &str = include_str!("../../input/2023/day11_test.txt");
+        const PARAMS: &str = include_str!("../../input/2023/day11_test.toml");
+        let ctx = SolveContext::from_sidecar(PARAMS).unwrap();
+        let solution = solve(INPUT, &ctx).unwrap();
+        assert_recorded(2023, "day11", "test", &solution);
+    }
+
+    #[test]
+    fn works_for_input() {
+        const INPUT: &str = include_str!("../../input/2023/day11.txt");
+        let solution = solve(INPUT, &SolveContext::default()).unwrap();
+        assert_recorded(2023, "day11", "real", &solution);
+    }
+}
@@ -1,84 +1,108 @@
 use std::collections::VecDeque;
 
-use bstr::ByteSlice;
 use fxhash::FxHashMap;
-use nom::{bytes::complete::tag, InputTakeAtPosition};
+use nom::{
+    bytes::complete::{is_a, tag},
+    character::complete::u32 as parse_u32,
+    multi::separated_list1,
+};
 
-use crate::{DayResult, IntoDayResult};
+use crate::{parse::Scanner, Day, DayResult};
 
 pub fn solve(input: &str) -> anyhow::Result<DayResult> {
-    let input = input.as_bytes();
-
-    let mut p1 = 0;
-    let mut p2 = 0;
-
-    let mut tiles_to_next_placeable = VecDeque::new();
-    let mut remaining_consecutive_placeable_spots = VecDeque::new();
-    let mut remaining_hashes = VecDeque::new();
-    let mut remaining_placeable_tiles = VecDeque::new();
-    let mut cache = FxHashMap::default();
-
-    for line in input.lines() {
-        let (nums, line) = line
-            .split_at_position::<_, nom::error::Error<&[u8]>>(|v| v == b' ')
-            .map_err(|err| anyhow::anyhow!("{err}"))?;
-        let (_, mut nums) = nom::multi::separated_list1::<_, _, _, nom::error::Error<&[u8]>, _, _>(
-            tag(","),
-            nom::character::complete::u64,
-        )(&nums.as_bytes()[1..])
-        .map_err(|err| anyhow::anyhow!("{err}"))?;
-
-        run_part(
-            line.as_bytes(),
-            &mut remaining_hashes,
-            &mut remaining_consecutive_placeable_spots,
-            &mut tiles_to_next_placeable,
-            &mut remaining_placeable_tiles,
-            &mut cache,
-            &nums,
-            &mut p1,
-        );
-
-        let mut p2_line = Vec::with_capacity(5 * line.len() + 4);
-        p2_line.extend_from_slice(line.as_bytes());
-        for _ in 0..4 {
-            p2_line.push(b'?');
-            p2_line.extend_from_slice(line.as_bytes());
+    Day12.solve(input)
+}
+
+#[derive(Default)]
+pub struct Day12;
+
+impl Day for Day12 {
+    type Parsed<'a> = Vec<Record<'a>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<'a>(&self, input: &'a str) -> anyhow::Result<Self::Parsed<'a>> {
+        let mut input = Scanner::new(input.as_bytes());
+        let mut records = vec![];
+
+        while !input.is_empty() {
+            let springs = input.parse(is_a("#.?"))?;
+            input.tag(b" ")?;
+            let nums_start = input.rest();
+            let nums = input.parse(separated_list1(tag(","), parse_u32))?;
+            if nums.contains(&0) {
+                return Err(input
+                    .error_at(nums_start, "groups must have at least 1 spring")
+                    .into());
+            }
+            input.newline()?;
+
+            records.push(Record {
+                springs,
+                nums: nums.into_iter().map(u64::from).collect(),
+            });
+        }
+
+        Ok(records)
+    }
+
+    fn part1(&self, records: &Self::Parsed<'_>) -> anyhow::Result<Self::Part1> {
+        let mut lookup_tables = LookupTables::default();
+        let mut p1 = 0;
+
+        for record in records {
+            run_part(record.springs, &mut lookup_tables, &record.nums, &mut p1);
         }
-        let l = nums.len();
-        nums.reserve(4 * l);
-        for _ in 0..4 {
-            for i in 0..l {
-                nums.push(nums[i]);
+
+        Ok(p1)
+    }
+
+    fn part2(&self, records: &Self::Parsed<'_>) -> anyhow::Result<Self::Part2> {
+        let mut lookup_tables = LookupTables::default();
+        let mut p2 = 0;
+
+        for Record { springs, nums } in records {
+            let mut p2_line = Vec::with_capacity(5 * springs.len() + 4);
+            p2_line.extend_from_slice(springs);
+            for _ in 0..4 {
+                p2_line.push(b'?');
+                p2_line.extend_from_slice(springs);
             }
+            let mut p2_nums = Vec::with_capacity(5 * nums.len());
+            for _ in 0..5 {
+                p2_nums.extend_from_slice(nums);
+            }
+
+            run_part(&p2_line, &mut lookup_tables, &p2_nums, &mut p2);
         }
 
-        run_part(
-            &p2_line,
-            &mut remaining_hashes,
-            &mut remaining_consecutive_placeable_spots,
-            &mut tiles_to_next_placeable,
-            &mut remaining_placeable_tiles,
-            &mut cache,
-            &nums,
-            &mut p2,
-        );
+        Ok(p2)
     }
+}
 
-    (p1, p2).into_result()
+pub struct Record<'a> {
+    springs: &'a [u8],
+    nums: Vec<u64>,
 }
 
-#[allow(clippy::too_many_arguments)]
-fn run_part(
-    line: &[u8],
-    remaining_hashes: &mut VecDeque<usize>,
-    remaining_consecutive_placeable_spots: &mut VecDeque<usize>,
-    tiles_to_next_placeable: &mut VecDeque<usize>,
-    remaining_placeable_tiles: &mut VecDeque<usize>,
-    cache: &mut FxHashMap<(usize, usize), usize>,
-    nums: &[u64],
-    part_accum: &mut usize,
-) {
+#[derive(Default)]
+struct LookupTables {
+    tiles_to_next_placeable: VecDeque<usize>,
+    remaining_consecutive_placeable_spots: VecDeque<usize>,
+    remaining_hashes: VecDeque<usize>,
+    remaining_placeable_tiles: VecDeque<usize>,
+    cache: FxHashMap<(usize, usize), usize>,
+}
+
+fn run_part(line: &[u8], lookup_tables: &mut LookupTables, nums: &[u64], part_accum: &mut usize) {
+    let LookupTables {
+        tiles_to_next_placeable,
+        remaining_consecutive_placeable_spots,
+        remaining_hashes,
+        remaining_placeable_tiles,
+        cache,
+    } = lookup_tables;
+
     prepare_lookup_tables(
         line,
         remaining_hashes,
@@ -256,22 +280,19 @@ fn solver(
 
 #[cfg(test)]
 mod tests {
-    use crate::{days::day12::solve, IntoDayResult};
+    use crate::{answers::assert_recorded, y2023::day12::solve};
 
     #[test]
     fn works_for_example() {
-        const INPUT: &str = include_str!("../../input/day12_test.txt");
+        const INPUT: &str = include_str!("../../input/2023/day12_test.txt");
         let solution = solve(INPUT).unwrap();
-        assert_eq!((21, 525_152).into_day_result(), solution);
+        assert_recorded(2023, "day12", "test", &solution);
     }
 
     #[test]
     fn works_for_input() {
-        const INPUT: &str = include_str!("../../input/day12.txt");
+        const INPUT: &str = include_str!("../../input/2023/day12.txt");
         let solution = solve(INPUT).unwrap();
-        assert_eq!(
-            (7_857, 28_606_137_449_920_usize).into_day_result(),
-            solution
-        );
+        assert_recorded(2023, "day12", "real", &solution);
     }
 }
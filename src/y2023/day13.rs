@@ -0,0 +1,123 @@
+use bstr::ByteSlice;
+
+use crate::{
+    parse::{grid_size_within, InputError},
+    Day, DayResult,
+};
+
+pub fn solve(input: &str) -> anyhow::Result<DayResult> {
+    Day13.solve(input)
+}
+
+#[derive(Default)]
+pub struct Day13;
+
+impl Day for Day13 {
+    type Parsed<'a> = Vec<Pattern<'a>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<'a>(&self, input: &'a str) -> anyhow::Result<Self::Parsed<'a>> {
+        let input = input.as_bytes();
+        let mut rest = input;
+        let mut patterns = vec![];
+
+        while !rest.is_empty() {
+            // patterns are separated by a blank line, each keeps its own trailing newline
+            let block = match rest.find(b"\n\n") {
+                Some(end) => {
+                    let block = &rest[..end + 1];
+                    rest = &rest[end + 2..];
+                    block
+                }
+                None => std::mem::take(&mut rest),
+            };
+
+            let (width, height) = grid_size_within(input, block)?;
+            if let Some(i) = block.iter().position(|b| !b"#.\n".contains(b)) {
+                return Err(InputError::at(input, &block[i..], "expected a tile").into());
+            }
+
+            patterns.push(Pattern {
+                block,
+                width,
+                height,
+            });
+        }
+
+        Ok(patterns)
+    }
+
+    fn part1(&self, patterns: &Self::Parsed<'_>) -> anyhow::Result<Self::Part1> {
+        Ok(patterns.iter().map(|pattern| pattern.summarize(0)).sum())
+    }
+
+    fn part2(&self, patterns: &Self::Parsed<'_>) -> anyhow::Result<Self::Part2> {
+        Ok(patterns.iter().map(|pattern| pattern.summarize(1)).sum())
+    }
+}
+
+pub struct Pattern<'a> {
+    block: &'a [u8],
+    width: usize,
+    height: usize,
+}
+
+impl Pattern<'_> {
+    fn summarize(&self, smudges: usize) -> usize {
+        let Pattern {
+            block,
+            width,
+            height,
+        } = *self;
+
+        for h in 1..height {
+            let tot = (0..width)
+                .map(|w| {
+                    let a = (h..height).map(|h| block[w + h * (width + 1)]);
+                    let b = (0..h).rev().map(|h| block[w + h * (width + 1)]);
+                    (a.zip(b).map(|(a, b)| (a != b) as usize).sum::<usize>() == 0) as usize
+                })
+                .sum::<usize>();
+
+            if tot + smudges == width {
+                return 100 * h;
+            }
+        }
+
+        for w in 1..width {
+            let tot = (0..height)
+                .map(|h| {
+                    let a = (w..width).map(|w| block[w + h * (width + 1)]);
+                    let b = (0..w).rev().map(|w| block[w + h * (width + 1)]);
+                    (a.zip(b).map(|(a, b)| (a != b) as usize).sum::<usize>() == 0) as usize
+                })
+                .sum::<usize>();
+
+            if tot + smudges == height {
+                return w;
+            }
+        }
+
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{answers::assert_recorded, y2023::day13::solve};
+
+    #[test]
+    fn works_for_example() {
+        const INPUT: &str = include_str!("../../input/2023/day13_test.txt");
+        let solution = solve(INPUT).unwrap();
+        assert_recorded(2023, "day13", "test", &solution);
+    }
+
+    #[test]
+    fn works_for_input() {
+        const INPUT: &str = include_str!("../../input/2023/day13.txt");
+        let solution = solve(INPUT).unwrap();
+        assert_recorded(2023, "day13", "real", &solution);
+    }
+}
@@ -2,65 +2,109 @@ use std::{borrow::BorrowMut, cell::Cell, collections::hash_map::Entry};
 
 use fxhash::FxHashMap;
 
-use crate::{DayResult, IntoDayResult};
+use crate::{
+    parse::{grid_size, InputError},
+    Day, DayResult,
+};
 
 pub fn solve(input: &str) -> anyhow::Result<DayResult> {
-    let mut moveable = vec![];
-    let mut world = input
-        .lines()
-        .enumerate()
-        .map(|(y, line)| {
-            line.as_bytes()
-                .iter()
-                .enumerate()
-                .map(|(x, &t)| {
-                    if t == b'O' {
-                        let rock = Moveable {
-                            x: Cell::new(x),
-                            y: Cell::new(y),
-                        };
-                        moveable.push(rock);
-                    }
+    Day14.solve(input)
+}
+
+#[derive(Default)]
+pub struct Day14;
+
+impl Day for Day14 {
+    type Parsed<'a> = Platform;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<'a>(&self, input: &'a str) -> anyhow::Result<Self::Parsed<'a>> {
+        let input = input.as_bytes();
+        let (width, _) = grid_size(input)?;
+
+        let mut moveable = vec![];
+        let world = input
+            .chunks(width + 1)
+            .enumerate()
+            .map(|(y, line)| {
+                line[..width]
+                    .iter()
+                    .enumerate()
+                    .map(|(x, &t)| match t {
+                        b'O' => {
+                            let rock = Moveable {
+                                x: Cell::new(x),
+                                y: Cell::new(y),
+                            };
+                            moveable.push(rock);
+                            Ok(false)
+                        }
+                        b'#' => Ok(false),
+                        b'.' => Ok(true),
+                        _ => Err(InputError::at(input, &line[x..], "expected a tile")),
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Platform { moveable, world })
+    }
+
+    fn part1(&self, platform: &Self::Parsed<'_>) -> anyhow::Result<Self::Part1> {
+        let Platform {
+            mut moveable,
+            mut world,
+        } = platform.clone();
+
+        north(&mut moveable, &mut world);
+
+        Ok(score(&moveable, world.len()))
+    }
+
+    fn part2(&self, platform: &Self::Parsed<'_>) -> anyhow::Result<Self::Part2> {
+        let Platform {
+            mut moveable,
+            mut world,
+        } = platform.clone();
+
+        cycle(&mut moveable, &mut world);
+
+        let mut seen = FxHashMap::default();
+
+        let mut p2 = 0;
 
-                    t == b'.'
-                })
-                .collect::<Vec<_>>()
-        })
-        .collect::<Vec<_>>();
-
-    north(&mut moveable, &mut world);
-    let p1 = score(&moveable, world.len());
-    west(&mut moveable, &mut world);
-    south(&mut moveable, &mut world);
-    east(&mut moveable, &mut world);
-
-    let mut seen = FxHashMap::default();
-
-    let mut p2 = 0;
-
-    for cycles in 1.. {
-        match seen.entry((repr(&moveable), score(&moveable, world.len()))) {
-            Entry::Occupied(prev_cycles) => {
-                let prev_cycles = prev_cycles.get();
-                let cycle_period = cycles - prev_cycles;
-                let rem = (1_000_000_000 - prev_cycles) % cycle_period;
-                for _ in 0..rem {
-                    cycle(&mut moveable, &mut world);
+        for cycles in 1.. {
+            match seen.entry((repr(&moveable), score(&moveable, world.len()))) {
+                Entry::Occupied(prev_cycles) => {
+                    let prev_cycles = prev_cycles.get();
+                    let cycle_period = cycles - prev_cycles;
+                    let rem = (1_000_000_000 - prev_cycles) % cycle_period;
+                    for _ in 0..rem {
+                        cycle(&mut moveable, &mut world);
+                    }
+                    p2 = score(&moveable, world.len());
+                    break;
+                }
+                Entry::Vacant(v) => {
+                    v.insert(cycles);
                 }
-                p2 = score(&moveable, world.len());
-                break;
-            }
-            Entry::Vacant(v) => {
-                v.insert(cycles);
             }
+
+            cycle(&mut moveable, &mut world);
         }
 
-        cycle(&mut moveable, &mut world);
+        Ok(p2)
     }
+}
 
-    (p1, p2).into_result()
+#[derive(Clone)]
+pub struct Platform {
+    moveable: Vec<Moveable>,
+    world: Vec<Vec<bool>>,
 }
 
+#[derive(Clone)]
 struct Moveable {
     x: Cell<usize>,
     y: Cell<usize>,
@@ -159,19 +203,19 @@ fn east(moveable: &mut [Moveable], world: &mut [impl BorrowMut<[bool]>]) {
 
 #[cfg(test)]
 mod tests {
-    use crate::{days::day14::solve, IntoDayResult};
+    use crate::{answers::assert_recorded, y2023::day14::solve};
 
     #[test]
     fn works_for_example() {
-        const INPUT: &str = include_str!("../../input/day14_test.txt");
+        const INPUT: &str = include_str!("../../input/2023/day14_test.txt");
         let solution = solve(INPUT).unwrap();
-        assert_eq!((136, 64).into_day_result(), solution);
+        assert_recorded(2023, "day14", "test", &solution);
     }
 
     #[test]
     fn works_for_input() {
-        const INPUT: &str = include_str!("../../input/day14.txt");
+        const INPUT: &str = include_str!("../../input/2023/day14.txt");
         let solution = solve(INPUT).unwrap();
-        assert_eq!((108_813, 104_533).into_day_result(), solution);
+        assert_recorded(2023, "day14", "real", &solution);
     }
 }
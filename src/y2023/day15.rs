@@ -0,0 +1,113 @@
+use bstr::ByteSlice;
+
+use crate::{parse::InputError, Day, DayResult};
+
+pub fn solve(input: &str) -> anyhow::Result<DayResult> {
+    Day15.solve(input)
+}
+
+#[derive(Default)]
+pub struct Day15;
+
+impl Day for Day15 {
+    type Parsed<'a> = Vec<&'a [u8]>;
+    type Part1 = u64;
+    type Part2 = usize;
+
+    fn parse<'a>(&self, input: &'a str) -> anyhow::Result<Self::Parsed<'a>> {
+        let input = input.as_bytes();
+        let cmds = input.trim_end().split(|&b| b == b',').collect::<Vec<_>>();
+
+        // part 2 splits every step into a label & an operation, so they're checked up front
+        for &cmd in &cmds {
+            let label_len = cmd.iter().take_while(|b| b.is_ascii_lowercase()).count();
+            match &cmd[label_len..] {
+                _ if label_len == 0 => {
+                    return Err(InputError::at(input, cmd, "expected a label").into())
+                }
+                [b'-'] => {}
+                [b'=', focal_length] if focal_length.is_ascii_digit() => {}
+                op => {
+                    return Err(InputError::at(input, op, "expected `-` or `=` and a digit").into())
+                }
+            }
+        }
+
+        Ok(cmds)
+    }
+
+    fn part1(&self, cmds: &Self::Parsed<'_>) -> anyhow::Result<Self::Part1> {
+        Ok(cmds.iter().map(|cmd| hash(cmd)).sum())
+    }
+
+    fn part2(&self, cmds: &Self::Parsed<'_>) -> anyhow::Result<Self::Part2> {
+        let mut boxes: [Vec<Lens>; 256] = std::array::from_fn(|_| Vec::new());
+
+        for cmd in cmds {
+            let (label, op) = if cmd[cmd.len() - 1] == b'-' {
+                cmd.split_at(cmd.len() - 1)
+            } else {
+                cmd.split_at(cmd.len() - 2)
+            };
+            let box_to_use = hash(label) as usize;
+
+            if op[0] == b'=' {
+                let focal_length = (op[1] - b'0') as usize;
+                if let Some(lens) = boxes[box_to_use].iter_mut().find(|l| l.label == label) {
+                    lens.focal_length = focal_length;
+                } else {
+                    boxes[box_to_use].push(Lens {
+                        label,
+                        focal_length,
+                    });
+                }
+            } else {
+                boxes[box_to_use].retain(|l| l.label != label);
+            }
+        }
+
+        Ok(boxes
+            .iter()
+            .enumerate()
+            .flat_map(|(box_ind, _box)| {
+                _box.iter()
+                    .enumerate()
+                    .map(move |(lens_ind, lens)| (1 + box_ind) * (lens_ind + 1) * lens.focal_length)
+            })
+            .sum::<usize>())
+    }
+}
+
+fn hash(s: &[u8]) -> u64 {
+    let mut h = 0;
+    for &b in s {
+        h += b as u64;
+        h *= 17;
+        h %= 256;
+    }
+    h
+}
+
+struct Lens<'a> {
+    label: &'a [u8],
+    focal_length: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{answers::assert_recorded, y2023::day15::solve};
+
+    #[test]
+    fn works_for_example() {
+        const INPUT: &str = include_str!("../../input/2023/day15_test.txt");
+        let solution = solve(INPUT).unwrap();
+        assert_recorded(2023, "day15", "test", &solution);
+    }
+
+    #[test]
+    fn works_for_input() {
+        const INPUT: &str = include_str!("../../input/2023/day15.txt");
+        let solution = solve(INPUT).unwrap();
+        assert_recorded(2023, "day15", "real", &solution);
+    }
+}
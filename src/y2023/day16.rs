@@ -1,43 +1,87 @@
-use anyhow::Context;
-
-use crate::{DayResult, IntoDayResult};
+use crate::{
+    parse::{grid_size, InputError},
+    Day, DayResult,
+};
 
 use Direction::*;
 
 pub fn solve(input: &str) -> anyhow::Result<DayResult> {
-    let input = input.as_bytes();
-    let width = input
-        .iter()
-        .position(|&b| b == b'\n')
-        .context("there should be a newline")?;
-    let height = input.len() / (width + 1);
-
-    let mut seen = vec![0_u8; width * height];
-
-    traverse(0, 0, Right, input, width, height, &mut seen);
-    let p1 = seen.iter().filter(|&&v| v != 0).count();
-
-    let mut p2 = 0;
-
-    for i in 0..width {
-        seen.iter_mut().for_each(|v| *v = 0);
-        traverse(i, 0, Down, input, width, height, &mut seen);
-        p2 = std::cmp::max(p2, seen.iter().filter(|&&v| v != 0).count());
-        seen.iter_mut().for_each(|v| *v = 0);
-        traverse(i, height - 1, Up, input, width, height, &mut seen);
-        p2 = std::cmp::max(p2, seen.iter().filter(|&&v| v != 0).count());
+    Day16.solve(input)
+}
+
+#[derive(Default)]
+pub struct Day16;
+
+impl Day for Day16 {
+    type Parsed<'a> = Contraption<'a>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<'a>(&self, input: &'a str) -> anyhow::Result<Self::Parsed<'a>> {
+        let input = input.as_bytes();
+        let (width, height) = grid_size(input)?;
+        if let Some(i) = input.iter().position(|b| !b"./\\|-\n".contains(b)) {
+            return Err(InputError::at(input, &input[i..], "expected a tile").into());
+        }
+
+        Ok(Contraption {
+            world: input,
+            width,
+            height,
+        })
+    }
+
+    fn part1(&self, contraption: &Self::Parsed<'_>) -> anyhow::Result<Self::Part1> {
+        let &Contraption {
+            world,
+            width,
+            height,
+        } = contraption;
+
+        let mut seen = vec![0_u8; width * height];
+
+        traverse(0, 0, Right, world, width, height, &mut seen);
+
+        Ok(seen.iter().filter(|&&v| v != 0).count())
     }
 
-    for j in 0..height {
-        seen.iter_mut().for_each(|v| *v = 0);
-        traverse(0, j, Right, input, width, height, &mut seen);
-        p2 = std::cmp::max(p2, seen.iter().filter(|&&v| v != 0).count());
-        seen.iter_mut().for_each(|v| *v = 0);
-        traverse(width - 1, j, Left, input, width, height, &mut seen);
-        p2 = std::cmp::max(p2, seen.iter().filter(|&&v| v != 0).count());
+    fn part2(&self, contraption: &Self::Parsed<'_>) -> anyhow::Result<Self::Part2> {
+        let &Contraption {
+            world,
+            width,
+            height,
+        } = contraption;
+
+        let mut seen = vec![0_u8; width * height];
+
+        let mut p2 = 0;
+
+        for i in 0..width {
+            seen.iter_mut().for_each(|v| *v = 0);
+            traverse(i, 0, Down, world, width, height, &mut seen);
+            p2 = std::cmp::max(p2, seen.iter().filter(|&&v| v != 0).count());
+            seen.iter_mut().for_each(|v| *v = 0);
+            traverse(i, height - 1, Up, world, width, height, &mut seen);
+            p2 = std::cmp::max(p2, seen.iter().filter(|&&v| v != 0).count());
+        }
+
+        for j in 0..height {
+            seen.iter_mut().for_each(|v| *v = 0);
+            traverse(0, j, Right, world, width, height, &mut seen);
+            p2 = std::cmp::max(p2, seen.iter().filter(|&&v| v != 0).count());
+            seen.iter_mut().for_each(|v| *v = 0);
+            traverse(width - 1, j, Left, world, width, height, &mut seen);
+            p2 = std::cmp::max(p2, seen.iter().filter(|&&v| v != 0).count());
+        }
+
+        Ok(p2)
     }
+}
 
-    (p1, p2).into_result()
+pub struct Contraption<'a> {
+    world: &'a [u8],
+    width: usize,
+    height: usize,
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -91,7 +135,7 @@ fn traverse(
                     Right => Down,
                 }
             }
-            _ => unreachable!("lmao"),
+            _ => unreachable!("tiles are checked when parsing"),
         }
 
         let Some((_x, _y)) = (match direction {
@@ -139,19 +183,19 @@ impl From<Direction> for u8 {
 
 #[cfg(test)]
 mod tests {
-    use crate::{days::day16::solve, IntoDayResult};
+    use crate::{answers::assert_recorded, y2023::day16::solve};
 
     #[test]
     fn works_for_example() {
-        const INPUT: &str = include_str!("../../input/day16_test.txt");
+        const INPUT: &str = include_str!("../../input/2023/day16_test.txt");
         let solution = solve(INPUT).unwrap();
-        assert_eq!((46, 51).into_day_result(), solution);
+        assert_recorded(2023, "day16", "test", &solution);
     }
 
     #[test]
     fn works_for_input() {
-        const INPUT: &str = include_str!("../../input/day16.txt");
+        const INPUT: &str = include_str!("../../input/2023/day16.txt");
         let solution = solve(INPUT).unwrap();
-        assert_eq!((7_884, 8_185).into_day_result(), solution);
+        assert_recorded(2023, "day16", "real", &solution);
     }
 }
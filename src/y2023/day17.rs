@@ -2,29 +2,72 @@ use std::{cmp::Reverse, collections::BinaryHeap};
 
 use anyhow::Context;
 
-use crate::{DayResult, IntoDayResult};
+use crate::{
+    parse::{grid_size, InputError},
+    Day, DayResult,
+};
 
 pub fn solve(input: &str) -> anyhow::Result<DayResult> {
-    let input = input.as_bytes();
-    let width = input
-        .iter()
-        .position(|&b| b == b'\n')
-        .context("failed to find newline")?;
-    let height = input.len() / (width + 1);
-    let end = (width - 1, height - 1);
+    Day17.solve(input)
+}
+
+#[derive(Default)]
+pub struct Day17;
+
+impl Day for Day17 {
+    type Parsed<'a> = City<'a>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<'a>(&self, input: &'a str) -> anyhow::Result<Self::Parsed<'a>> {
+        let input = input.as_bytes();
+        let (width, height) = grid_size(input)?;
+        if let Some(i) = input
+            .iter()
+            .position(|&b| b != b'\n' && !b.is_ascii_digit())
+        {
+            return Err(InputError::at(input, &input[i..], "expected a digit").into());
+        }
+
+        Ok(City {
+            input,
+            width,
+            height,
+        })
+    }
+
+    fn part1(&self, city: &Self::Parsed<'_>) -> anyhow::Result<Self::Part1> {
+        city.least_heat_loss::<1, 3>()
+            .context("the factory is unreachable")
+    }
 
-    let mut visited = vec![Lens::new(); width * height];
-    let mut states = BinaryHeap::from_iter([Reverse(State::new((0, 0), 0, None, width, height))]);
+    fn part2(&self, city: &Self::Parsed<'_>) -> anyhow::Result<Self::Part2> {
+        city.least_heat_loss::<4, 10>()
+            .context("the factory is unreachable with an ultra crucible")
+    }
+}
 
-    let p1 = solver::<1, 3>(&mut states, &mut visited, width, height, end, input);
+pub struct City<'a> {
+    input: &'a [u8],
+    width: usize,
+    height: usize,
+}
 
-    visited.iter_mut().for_each(|v| *v = Lens::new());
-    states.clear();
-    states.push(Reverse(State::new((0, 0), 0, None, width, height)));
+impl City<'_> {
+    fn least_heat_loss<const MOVE_MIN: u16, const MOVE_MAX: u16>(&self) -> Option<usize> {
+        let &City {
+            input,
+            width,
+            height,
+        } = self;
+        let end = (width - 1, height - 1);
 
-    let p2 = solver::<4, 10>(&mut states, &mut visited, width, height, end, input);
+        let mut visited = vec![Lens::new(); width * height];
+        let mut states =
+            BinaryHeap::from_iter([Reverse(State::new((0, 0), 0, None, width, height))]);
 
-    (p1, p2).into_result()
+        solver::<MOVE_MIN, MOVE_MAX>(&mut states, &mut visited, width, height, end, input)
+    }
 }
 
 fn solver<const MOVE_MIN: u16, const MOVE_MAX: u16>(
@@ -34,7 +77,7 @@ fn solver<const MOVE_MIN: u16, const MOVE_MAX: u16>(
     height: usize,
     end: (usize, usize),
     input: &[u8],
-) -> usize {
+) -> Option<usize> {
     while let Some(Reverse(State {
         coord: coord @ (x, y),
         score,
@@ -73,7 +116,7 @@ fn solver<const MOVE_MIN: u16, const MOVE_MAX: u16>(
         };
 
         if coord == end {
-            return score;
+            return Some(score);
         }
 
         match direction {
@@ -81,7 +124,7 @@ fn solver<const MOVE_MIN: u16, const MOVE_MAX: u16>(
                 if dist < MOVE_MAX {
                     if let Some(moved @ (x, y)) = coord.move_in(direction, width, height) {
                         if moved == end {
-                            return score + (input[x + y * (width + 1)] - b'0') as usize;
+                            return Some(score + (input[x + y * (width + 1)] - b'0') as usize);
                         }
                         if is_good(visited, direction, moved, width, dist + 1) {
                             states.push(Reverse(State::new(
@@ -99,14 +142,14 @@ fn solver<const MOVE_MIN: u16, const MOVE_MAX: u16>(
                 if let Some(value) = move_and_solve::<MOVE_MIN, MOVE_MAX>(
                     coord, left, width, height, input, end, score, visited, states,
                 ) {
-                    return value;
+                    return Some(value);
                 }
 
                 let right = direction.right();
                 if let Some(value) = move_and_solve::<MOVE_MIN, MOVE_MAX>(
                     coord, right, width, height, input, end, score, visited, states,
                 ) {
-                    return value;
+                    return Some(value);
                 }
             }
             None => {
@@ -114,14 +157,14 @@ fn solver<const MOVE_MIN: u16, const MOVE_MAX: u16>(
                 if let Some(value) = move_and_solve::<MOVE_MIN, MOVE_MAX>(
                     coord, down, width, height, input, end, score, visited, states,
                 ) {
-                    return value;
+                    return Some(value);
                 }
 
                 let right = Direction::Right;
                 if let Some(value) = move_and_solve::<MOVE_MIN, MOVE_MAX>(
                     coord, right, width, height, input, end, score, visited, states,
                 ) {
-                    return value;
+                    return Some(value);
                 }
             }
         }
@@ -137,7 +180,7 @@ fn solver<const MOVE_MIN: u16, const MOVE_MAX: u16>(
         }
     }
 
-    unreachable!("lmaoooooo")
+    None
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -305,19 +348,19 @@ impl MoveIn for (usize, usize) {
 
 #[cfg(test)]
 mod tests {
-    use crate::{days::day17::solve, IntoDayResult};
+    use crate::{answers::assert_recorded, y2023::day17::solve};
 
     #[test]
     fn works_for_example() {
-        const INPUT: &str = include_str!("../../input/day17_test.txt");
+        const INPUT: &str = include_str!("../../input/2023/day17_test.txt");
         let solution = solve(INPUT).unwrap();
-        assert_eq!((102, 94).into_day_result(), solution);
+        assert_recorded(2023, "day17", "test", &solution);
     }
 
     #[test]
     fn works_for_input() {
-        const INPUT: &str = include_str!("../../input/day17.txt");
+        const INPUT: &str = include_str!("../../input/2023/day17.txt");
         let solution = solve(INPUT).unwrap();
-        assert_eq!((861, 1_037).into_day_result(), solution);
+        assert_recorded(2023, "day17", "real", &solution);
     }
 }
@@ -0,0 +1,150 @@
+use anyhow::Context;
+use itertools::Itertools;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    combinator::{map, map_opt},
+    IResult,
+};
+
+use crate::{parse::Scanner, Day, DayResult};
+
+pub fn solve(input: &str) -> anyhow::Result<DayResult> {
+    Day18.solve(input)
+}
+
+#[derive(Default)]
+pub struct Day18;
+
+impl Day for Day18 {
+    type Parsed<'a> = Vec<(DDTuple, DDTuple)>;
+    type Part1 = isize;
+    type Part2 = isize;
+
+    fn parse<'a>(&self, input: &'a str) -> anyhow::Result<Self::Parsed<'a>> {
+        let mut input = Scanner::new(input.as_bytes());
+        let mut dig_plan = vec![];
+        while !input.is_empty() {
+            dig_plan.push(input.parse(parse_line)?);
+            input.newline()?;
+        }
+        Ok(dig_plan)
+    }
+
+    fn part1(&self, dig_plan: &Self::Parsed<'_>) -> anyhow::Result<Self::Part1> {
+        lagoon_size(dig_plan.iter().map(|&(dd, _)| dd)).context("the lagoon is too large")
+    }
+
+    fn part2(&self, dig_plan: &Self::Parsed<'_>) -> anyhow::Result<Self::Part2> {
+        lagoon_size(dig_plan.iter().map(|&(_, dd)| dd)).context("the lagoon is too large")
+    }
+}
+
+/// `None` if the lagoon's size doesn't fit in an `isize`
+fn lagoon_size(dig_plan: impl Iterator<Item = DDTuple>) -> Option<isize> {
+    let mut x: isize = 0;
+    let mut y: isize = 0;
+
+    let mut points = vec![(x, y)];
+    let mut boundary_points: isize = 0;
+
+    for (direction, distance) in dig_plan {
+        let distance = distance as isize;
+
+        (x, y) = match direction {
+            Direction::Up => (x, y.checked_add(distance)?),
+            Direction::Down => (x, y.checked_sub(distance)?),
+            Direction::Left => (x.checked_sub(distance)?, y),
+            Direction::Right => (x.checked_add(distance)?, y),
+        };
+
+        boundary_points = boundary_points.checked_add(distance)?;
+
+        points.push((x, y));
+    }
+
+    let interior = (area(&points)? + 1).checked_sub(boundary_points / 2)?;
+
+    interior.checked_add(boundary_points)
+}
+
+fn area(points: &[(isize, isize)]) -> Option<isize> {
+    let twice_area =
+        points
+            .iter()
+            .tuple_windows()
+            .try_fold(0_isize, |sum, (&(x1, y1), &(x2, y2))| {
+                let term = y1.checked_add(y2)?.checked_mul(x1.checked_sub(x2)?)?;
+                sum.checked_add(term)
+            })?;
+    Some(twice_area.checked_abs()? / 2)
+}
+
+fn parse_direction(line: &[u8]) -> IResult<&[u8], Direction> {
+    alt((
+        map(tag("U"), |_| Direction::Up),
+        map(tag("D"), |_| Direction::Down),
+        map(tag("L"), |_| Direction::Left),
+        map(tag("R"), |_| Direction::Right),
+    ))(line)
+}
+
+fn parse_direction2(d: u32) -> Option<Direction> {
+    Some(match d {
+        0 => Direction::Right,
+        1 => Direction::Down,
+        2 => Direction::Left,
+        3 => Direction::Up,
+        _ => return None,
+    })
+}
+
+fn parse_distance_direction(line: &[u8]) -> IResult<&[u8], (Direction, u32)> {
+    map_opt(nom::number::complete::hex_u32, |n| {
+        let direction = n & 0b1111;
+        parse_direction2(direction).map(|d| (d, n >> 4))
+    })(line)
+}
+
+pub type DDTuple = (Direction, u32);
+
+fn parse_line(line: &[u8]) -> IResult<&[u8], (DDTuple, DDTuple)> {
+    map(
+        nom::sequence::tuple((
+            parse_direction,
+            tag(" "),
+            nom::character::complete::u32,
+            tag(" (#"),
+            parse_distance_direction,
+            tag(")"),
+        )),
+        |(direction, _, distance, _, hex, _)| ((direction, distance), hex),
+    )(line)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{answers::assert_recorded, y2023::day18::solve};
+
+    #[test]
+    fn works_for_example() {
+        const INPUT: &str = include_str!("../../input/2023/day18_test.txt");
+        let solution = solve(INPUT).unwrap();
+        assert_recorded(2023, "day18", "test", &solution);
+    }
+
+    #[test]
+    fn works_for_input() {
+        const INPUT: &str = include_str!("../../input/2023/day18.txt");
+        let solution = solve(INPUT).unwrap();
+        assert_recorded(2023, "day18", "real", &solution);
+    }
+}
@@ -1,45 +1,102 @@
-use fxhash::FxHashMap;
+use fxhash::{FxHashMap, FxHashSet};
 use nom::{
     branch::alt, bytes::complete::tag, combinator::map, multi::separated_list1, sequence::tuple,
     IResult,
 };
 
-use crate::{DayResult, IntoDayResult};
+use anyhow::Context;
 
-pub fn solve(_input: &str) -> anyhow::Result<DayResult> {
-    let mut rulesets = FxHashMap::default();
-    let mut inputs = vec![];
-    let mut lines = _input.lines();
-    for (_, ruleset) in lines.by_ref().map_while(|line| parse_ruleset(line).ok()) {
-        rulesets.insert(ruleset.name, ruleset);
-    }
-    for line in lines {
-        let (_, input) = parse_input(line).map_err(|err| anyhow::anyhow!("{err}"))?;
-        inputs.push(input);
-    }
+use crate::{parse::InputError, Day, DayResult};
 
-    let mut p1 = 0;
+pub fn solve(input: &str) -> anyhow::Result<DayResult> {
+    Day19.solve(input)
+}
 
-    for input in inputs {
-        let mut pos = &rulesets["in"];
-        loop {
-            match pos.apply(input) {
-                Action::Termination(termination) => {
-                    if termination == Termination::Accept {
-                        p1 += input.total();
-                    }
-                    break;
+#[derive(Default)]
+pub struct Day19;
+
+impl Day for Day19 {
+    type Parsed<'a> = System<'a>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse<'a>(&self, input: &'a str) -> anyhow::Result<Self::Parsed<'a>> {
+        let bytes = input.as_bytes();
+        let mut rulesets = FxHashMap::default();
+        let mut inputs = vec![];
+        let mut lines = input.lines();
+        for line in lines.by_ref().take_while(|line| !line.is_empty()) {
+            let ruleset = parse_line(bytes, line, parse_ruleset)?;
+            if rulesets.contains_key(ruleset.name) {
+                return Err(InputError::at(bytes, line.as_bytes(), "duplicate workflow").into());
+            }
+            rulesets.insert(ruleset.name, ruleset);
+        }
+        for line in lines {
+            inputs.push(parse_line(bytes, line, parse_input)?);
+        }
+
+        for ruleset in rulesets.values() {
+            for next in ruleset.next() {
+                if !rulesets.contains_key(next) {
+                    return Err(InputError::at(bytes, next.as_bytes(), "unknown workflow").into());
                 }
-                Action::Next(next) => {
-                    pos = &rulesets[next];
+            }
+        }
+        // with no cycles every part eventually terminates, and part 2's recursion is bounded
+        let mut done = FxHashSet::default();
+        for name in rulesets.keys() {
+            if let Err(name) = check_acyclic(name, &rulesets, &mut vec![], &mut done) {
+                return Err(
+                    InputError::at(bytes, name.as_bytes(), "workflows loop back to here").into(),
+                );
+            }
+        }
+
+        Ok(System { rulesets, inputs })
+    }
+
+    fn part1(&self, system: &Self::Parsed<'_>) -> anyhow::Result<Self::Part1> {
+        let rulesets = &system.rulesets;
+        let mut p1 = 0;
+
+        for &input in &system.inputs {
+            let mut pos = rulesets
+                .get("in")
+                .context("there should be an in ruleset")?;
+            loop {
+                match pos.apply(input) {
+                    Action::Termination(termination) => {
+                        if termination == Termination::Accept {
+                            p1 = input
+                                .total()
+                                .and_then(|total| total.checked_add(p1))
+                                .context("the total rating is too large")?;
+                        }
+                        break;
+                    }
+                    Action::Next(next) => {
+                        pos = &rulesets[next];
+                    }
                 }
             }
         }
+
+        Ok(p1)
     }
 
-    let p2 = rulesets["in"].max_possible(InputMinMax::default(), &rulesets);
+    fn part2(&self, system: &Self::Parsed<'_>) -> anyhow::Result<Self::Part2> {
+        let rulesets = &system.rulesets;
+        Ok(rulesets
+            .get("in")
+            .context("there should be an in ruleset")?
+            .max_possible(InputMinMax::default(), rulesets))
+    }
+}
 
-    (p1, p2).into_result()
+pub struct System<'a> {
+    rulesets: FxHashMap<&'a str, Ruleset<'a>>,
+    inputs: Vec<Input>,
 }
 
 #[derive(Debug)]
@@ -50,6 +107,18 @@ struct Ruleset<'a> {
 }
 
 impl<'a> Ruleset<'a> {
+    /// every workflow this one can send a part to
+    fn next(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.rules
+            .iter()
+            .map(|rule| rule.action)
+            .chain([self.default])
+            .filter_map(|action| match action {
+                Action::Next(next) => Some(next),
+                Action::Termination(_) => None,
+            })
+    }
+
     fn apply(&self, input: Input) -> Action<'a> {
         for rule in &self.rules {
             if let Some(action) = rule.apply(input) {
@@ -69,12 +138,12 @@ impl<'a> Ruleset<'a> {
 
             match rule.op {
                 Op::Gt => {
-                    crit.max = rule.than;
-                    crit_branch.min = rule.than + 1;
+                    crit.max = crit.max.min(rule.than);
+                    crit_branch.min = crit_branch.min.max(rule.than.saturating_add(1));
                 }
                 Op::Lt => {
-                    crit.min = rule.than;
-                    crit_branch.max = rule.than - 1;
+                    crit.min = crit.min.max(rule.than);
+                    crit_branch.max = crit_branch.max.min(rule.than.saturating_sub(1));
                 }
             }
 
@@ -99,11 +168,52 @@ impl<'a> Ruleset<'a> {
     }
 }
 
+/// runs `parser` over a whole line of `input`
+fn parse_line<'a, T>(
+    input: &[u8],
+    line: &'a str,
+    parser: impl FnOnce(&'a str) -> IResult<&'a str, T>,
+) -> Result<T, InputError> {
+    let (rest, value) = parser(line).map_err(|err| InputError::nom(input, err))?;
+    if !rest.is_empty() {
+        return Err(InputError::at(
+            input,
+            rest.as_bytes(),
+            "expected the end of the line",
+        ));
+    }
+    Ok(value)
+}
+
+/// `Err` with a workflow on a cycle if one is reachable from `name`
+fn check_acyclic<'a>(
+    name: &'a str,
+    rulesets: &FxHashMap<&'a str, Ruleset<'a>>,
+    path: &mut Vec<&'a str>,
+    done: &mut FxHashSet<&'a str>,
+) -> Result<(), &'a str> {
+    if done.contains(name) {
+        return Ok(());
+    }
+    if path.contains(&name) {
+        return Err(name);
+    }
+
+    path.push(name);
+    for next in rulesets[name].next() {
+        check_acyclic(next, rulesets, path, done)?;
+    }
+    path.pop();
+    done.insert(name);
+
+    Ok(())
+}
+
 fn parse_rule_name(input: &str) -> IResult<&str, &str> {
     nom::bytes::complete::is_a("qwertyuiopasdfghjklzxcvbnm")(input)
 }
 
-fn parse_ruleset(input: &str) -> IResult<&str, Ruleset> {
+fn parse_ruleset(input: &str) -> IResult<&str, Ruleset<'_>> {
     map(
         tuple((
             parse_rule_name,
@@ -143,7 +253,7 @@ impl<'a> Rule<'a> {
     }
 }
 
-fn parse_rule(input: &str) -> IResult<&str, Rule> {
+fn parse_rule(input: &str) -> IResult<&str, Rule<'_>> {
     map(
         tuple((
             parse_criteria,
@@ -200,7 +310,7 @@ enum Termination {
     Reject,
 }
 
-fn parse_action(input: &str) -> IResult<&str, Action> {
+fn parse_action(input: &str) -> IResult<&str, Action<'_>> {
     alt((
         map(tag("R"), |_| Action::Termination(Termination::Reject)),
         map(tag("A"), |_| Action::Termination(Termination::Accept)),
@@ -226,9 +336,9 @@ impl Input {
         }
     }
 
-    fn total(self) -> u64 {
+    fn total(self) -> Option<u64> {
         let Input { x, m, a, s } = self;
-        x + m + a + s
+        x.checked_add(m)?.checked_add(a)?.checked_add(s)
     }
 }
 
@@ -280,7 +390,7 @@ struct MinMax {
 
 impl MinMax {
     fn possible(self) -> u64 {
-        (self.max - self.min) + 1
+        (self.max + 1).saturating_sub(self.min)
     }
 }
 
@@ -292,25 +402,19 @@ impl Default for MinMax {
 
 #[cfg(test)]
 mod tests {
-    use crate::{days::day19::solve, IntoDayResult};
+    use crate::{answers::assert_recorded, y2023::day19::solve};
 
     #[test]
     fn works_for_example() {
-        const INPUT: &str = include_str!("../../input/day19_test.txt");
+        const INPUT: &str = include_str!("../../input/2023/day19_test.txt");
         let solution = solve(INPUT).unwrap();
-        assert_eq!(
-            (19_114, 167_409_079_868_000_isize).into_day_result(),
-            solution
-        );
+        assert_recorded(2023, "day19", "test", &solution);
     }
 
     #[test]
     fn works_for_input() {
-        const INPUT: &str = include_str!("../../input/day19.txt");
+        const INPUT: &str = include_str!("../../input/2023/day19.txt");
         let solution = solve(INPUT).unwrap();
-        assert_eq!(
-            (476_889, 132_380_153_677_887_isize).into_day_result(),
-            solution
-        );
+        assert_recorded(2023, "day19", "real", &solution);
     }
 }
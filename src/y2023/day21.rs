@@ -0,0 +1,111 @@
+use fxhash::FxHashSet;
+
+use crate::{
+    context::{FromContext, SolveContext},
+    parse::{grid_size, InputError},
+    Day, DayResult,
+};
+
+pub fn solve(input: &str, ctx: &SolveContext) -> anyhow::Result<DayResult> {
+    Day21::from_context(ctx)?.solve(input)
+}
+
+pub struct Day21 {
+    pub steps: usize,
+}
+
+impl FromContext for Day21 {
+    fn from_context(ctx: &SolveContext) -> anyhow::Result<Self> {
+        Ok(Day21 {
+            steps: ctx.param_or("steps", 64)?,
+        })
+    }
+}
+
+impl Day for Day21 {
+    type Parsed<'a> = Garden;
+    type Part1 = usize;
+    type Part2 = ();
+
+    fn parse<'a>(&self, input: &'a str) -> anyhow::Result<Self::Parsed<'a>> {
+        let input = input.as_bytes();
+        let (width, _) = grid_size(input)?;
+
+        let mut start = None;
+        let mut rocks = FxHashSet::default();
+        for (y, line) in input.chunks(width + 1).enumerate() {
+            for (x, &b) in line[..width].iter().enumerate() {
+                let tile = (x as isize, y as isize);
+                match b {
+                    b'#' => {
+                        rocks.insert(tile);
+                    }
+                    b'S' if start.is_none() => start = Some(tile),
+                    b'S' => {
+                        return Err(InputError::at(input, &line[x..], "found a second start").into())
+                    }
+                    b'.' => {}
+                    _ => return Err(InputError::at(input, &line[x..], "expected a tile").into()),
+                }
+            }
+        }
+        let start = start.ok_or_else(|| InputError::at_offset(input, 0, "expected a start `S`"))?;
+
+        Ok(Garden { rocks, start })
+    }
+
+    fn part1(&self, garden: &Self::Parsed<'_>) -> anyhow::Result<Self::Part1> {
+        Ok(solve_1(&garden.rocks, garden.start, self.steps))
+    }
+
+    fn part2(&self, _garden: &Self::Parsed<'_>) -> anyhow::Result<Self::Part2> {
+        Ok(())
+    }
+}
+
+pub struct Garden {
+    rocks: FxHashSet<(isize, isize)>,
+    start: (isize, isize),
+}
+
+fn solve_1(world: &FxHashSet<(isize, isize)>, start: (isize, isize), turns: usize) -> usize {
+    let mut states = FxHashSet::from_iter([start]);
+    let mut states_swap = FxHashSet::default();
+    for _ in 0..turns {
+        states_swap.clear();
+
+        for (x, y) in states.drain() {
+            for next_state in [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)] {
+                if world.contains(&next_state) {
+                    continue;
+                }
+                states_swap.insert(next_state);
+            }
+        }
+
+        std::mem::swap(&mut states, &mut states_swap);
+    }
+
+    states.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{answers::assert_recorded, context::SolveContext, y2023::day21::solve};
+
+    #[test]
+    fn works_for_example() {
+        const INPUT: &str = include_str!("../../input/2023/day21_test.txt");
+        const PARAMS: &str = include_str!("../../input/2023/day21_test.toml");
+        let ctx = SolveContext::from_sidecar(PARAMS).unwrap();
+        let solution = solve(INPUT, &ctx).unwrap();
+        assert_recorded(2023, "day21", "test", &solution);
+    }
+
+    #[test]
+    fn works_for_input() {
+        const INPUT: &str = include_str!("../../input/2023/day21.txt");
+        let solution = solve(INPUT, &SolveContext::default()).unwrap();
+        assert_recorded(2023, "day21", "real", &solution);
+    }
+}
@@ -0,0 +1,47 @@
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day21;
+
+use crate::{solver, Solver};
+
+pub const YEAR: u32 = 2023;
+
+pub const SOLVERS: &[Solver] = &[
+    solver!(day01, Day01),
+    solver!(day02, Day02),
+    solver!(day03, Day03),
+    solver!(day04, Day04),
+    solver!(day05, Day05),
+    solver!(day06, Day06),
+    solver!(day07, Day07),
+    solver!(day08, Day08),
+    solver!(day09, Day09),
+    solver!(day10, Day10),
+    solver!(day11, Day11),
+    solver!(day12, Day12),
+    solver!(day13, Day13),
+    solver!(day14, Day14),
+    solver!(day15, Day15),
+    solver!(day16, Day16),
+    solver!(day17, Day17),
+    solver!(day18, Day18),
+    solver!(day19, Day19),
+    solver!(day21, Day21),
+];
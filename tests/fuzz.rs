@@ -0,0 +1,241 @@
+//! Mutates every stored input and checks no solver panics on it
+//!
+//! `$ cargo test --profile fuzz --test fuzz -- --ignored --nocapture`
+//!
+//! The `fuzz` profile is a release build that keeps overflow checks, without them an overflowing
+//! number wraps instead of panicking and the harness can't see it. A plain debug run works too,
+//! just slower.
+//!
+//! - `FUZZ_DAY=day05` to only fuzz one day
+//! - `FUZZ_ITERS=1000` mutations per input, 200 by default
+//! - `FUZZ_SEED=42` to change the mutations, runs with the same seed are reproducible
+//!
+//! Inputs that panic are saved under `target/tmp/fuzz/` along with a `.toml` sidecar of the params
+//! they were solved with, to be re-run with `--input`
+
+use std::{
+    panic::{catch_unwind, AssertUnwindSafe},
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
+
+use advent_of_code_2023::{
+    context::SolveContext, examples::examples, get_context, get_input, solvers, Solver,
+};
+
+const DEFAULT_ITERS: usize = 200;
+
+#[derive(Debug, Clone, Copy)]
+enum Mutation {
+    Truncate,
+    FlipBytes,
+    DuplicateLine,
+    Crlf,
+    InflateNumber,
+}
+
+impl Mutation {
+    const ALL: [Mutation; 5] = [
+        Mutation::Truncate,
+        Mutation::FlipBytes,
+        Mutation::DuplicateLine,
+        Mutation::Crlf,
+        Mutation::InflateNumber,
+    ];
+
+    fn apply(self, rng: &mut fastrand::Rng, input: &[u8]) -> Vec<u8> {
+        match self {
+            Mutation::Truncate => input[..rng.usize(..=input.len())].to_vec(),
+            Mutation::FlipBytes => {
+                let mut input = input.to_vec();
+                if !input.is_empty() {
+                    for _ in 0..rng.usize(1..=4) {
+                        let i = rng.usize(..input.len());
+                        input[i] = mutated_byte(rng, input[i]);
+                    }
+                }
+                input
+            }
+            Mutation::DuplicateLine => {
+                let lines = input.split_inclusive(|&b| b == b'\n').collect::<Vec<_>>();
+                if lines.is_empty() {
+                    return input.to_vec();
+                }
+                let duplicate = rng.usize(..lines.len());
+                let mut output = Vec::with_capacity(input.len() + lines[duplicate].len());
+                for (i, line) in lines.iter().enumerate() {
+                    output.extend_from_slice(line);
+                    if i == duplicate {
+                        output.extend_from_slice(line);
+                    }
+                }
+                output
+            }
+            Mutation::Crlf => {
+                let mut output = Vec::with_capacity(input.len() * 2);
+                for &b in input {
+                    if b == b'\n' {
+                        output.push(b'\r');
+                    }
+                    output.push(b);
+                }
+                output
+            }
+            Mutation::InflateNumber => {
+                let runs = input
+                    .iter()
+                    .enumerate()
+                    .filter(|&(i, b)| {
+                        b.is_ascii_digit() && (i == 0 || !input[i - 1].is_ascii_digit())
+                    })
+                    .map(|(start, _)| {
+                        let len = input[start..]
+                            .iter()
+                            .take_while(|b| b.is_ascii_digit())
+                            .count();
+                        start..start + len
+                    })
+                    .collect::<Vec<_>>();
+                if runs.is_empty() {
+                    return input.to_vec();
+                }
+                let run = runs[rng.usize(..runs.len())].clone();
+                let number = inflated_number(rng, &input[run.clone()]);
+                let mut output = Vec::with_capacity(input.len() + number.len());
+                output.extend_from_slice(&input[..run.start]);
+                output.extend_from_slice(&number);
+                output.extend_from_slice(&input[run.end..]);
+                output
+            }
+        }
+    }
+}
+
+/// a number at or near the edge of a type parsers commonly use, so arithmetic on it overflows
+fn inflated_number(rng: &mut fastrand::Rng, digits: &[u8]) -> Vec<u8> {
+    let limit = match rng.u8(..5) {
+        0 => i32::MAX.to_string(),
+        1 => u32::MAX.to_string(),
+        2 => i64::MAX.to_string(),
+        3 => u64::MAX.to_string(),
+        // the same digits repeated, which is only too big for some days
+        _ => return digits.repeat(rng.usize(2..=4)),
+    };
+    // sometimes just under it, which still overflows once a little more is added
+    let mut limit = limit.into_bytes();
+    if rng.bool() {
+        let last = limit.len() - 1;
+        limit[last] -= rng.u8(..=limit[last] - b'0');
+    }
+    limit
+}
+
+/// mostly bytes that mean something to some day's parser, so mutations get past the first check
+fn mutated_byte(rng: &mut fastrand::Rng, original: u8) -> u8 {
+    const INTERESTING: &[u8] = b" \n.#0123456789-+,:;=<>{}()|/\\SLRJF7OAZ";
+    match rng.u8(..4) {
+        0 => original.wrapping_add(1),
+        1 => original.wrapping_sub(1),
+        2 => rng.u8(..),
+        _ => INTERESTING[rng.usize(..INTERESTING.len())],
+    }
+}
+
+struct Seed {
+    name: String,
+    input: String,
+    ctx: SolveContext,
+}
+
+/// the real & test inputs plus any numbered examples for `solver`
+fn seeds(solver: &Solver) -> Vec<Seed> {
+    let mut seeds = vec![];
+    for (name, is_test) in [("real", false), ("test", true)] {
+        if let Ok(input) = get_input(solver.year, solver.name, is_test) {
+            seeds.push(Seed {
+                name: name.to_string(),
+                input,
+                ctx: get_context(solver.year, solver.name, is_test).unwrap(),
+            });
+        }
+    }
+    for example in examples(solver.year, solver.name).unwrap() {
+        seeds.push(Seed {
+            name: format!("example_{}", example.number),
+            input: example.input,
+            ctx: example.ctx,
+        });
+    }
+    seeds
+}
+
+fn env_or<T: std::str::FromStr>(key: &str, default: T) -> T {
+    std::env::var(key)
+        .ok()
+        .map(|value| {
+            value
+                .parse()
+                .unwrap_or_else(|_| panic!("{key} is invalid: {value:?}"))
+        })
+        .unwrap_or(default)
+}
+
+#[test]
+#[ignore = "slow, run locally with --ignored"]
+fn solvers_never_panic() {
+    let only = std::env::var("FUZZ_DAY").ok();
+    let iters = env_or("FUZZ_ITERS", DEFAULT_ITERS);
+    let seed = env_or("FUZZ_SEED", 0_u64);
+    let out_dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("fuzz");
+
+    // the default hook would print every caught panic, keep the last message for the report instead
+    let last_panic = Arc::new(Mutex::new(String::new()));
+    let hook_panic = Arc::clone(&last_panic);
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        *hook_panic.lock().unwrap() = info.to_string();
+    }));
+
+    let mut failures = vec![];
+    for solver in solvers() {
+        // either `day05` or `2023/day05` when more than one year has that day
+        if only
+            .as_deref()
+            .is_some_and(|day| day != solver.name && day != solver.label())
+        {
+            continue;
+        }
+
+        for Seed { name, input, ctx } in seeds(solver) {
+            let mut rng = fastrand::Rng::with_seed(seed ^ u64::from(solver.day) << 32);
+            for i in 0..iters {
+                let mutation = Mutation::ALL[rng.usize(..Mutation::ALL.len())];
+                let mutated = mutation.apply(&mut rng, input.as_bytes());
+                // solvers take `&str`, so mutations that break utf8 are replaced rather than skipped
+                let mutated = String::from_utf8_lossy(&mutated);
+
+                if catch_unwind(AssertUnwindSafe(|| (solver.solve)(&mutated, &ctx))).is_ok() {
+                    continue;
+                }
+
+                std::fs::create_dir_all(&out_dir).unwrap();
+                let path = out_dir.join(format!("{}_{}_{name}_{i}.txt", solver.year, solver.name));
+                std::fs::write(&path, mutated.as_bytes()).unwrap();
+                // the seed's params too, `--input` reads them from the sidecar next to the input
+                std::fs::write(path.with_extension("toml"), ctx.to_sidecar().unwrap()).unwrap();
+                let message = last_panic.lock().unwrap().clone();
+                failures.push(format!(
+                    "{mutation:?} of {name} input: {path:?}\n  {message}"
+                ));
+            }
+        }
+    }
+
+    std::panic::set_hook(default_hook);
+    assert!(
+        failures.is_empty(),
+        "{} inputs panicked:\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+}